@@ -49,12 +49,16 @@ pub(crate) fn expand_borrow(
                 })
             });
 
-            let field = field_name
+            let fields = field_name
                 .into_iter()
                 .zip(field_type)
                 .zip(field_is_default)
+                .collect::<Vec<_>>();
+
+            let field = fields
+                .iter()
                 .map(|((field_name, field_type), field_is_default)| {
-                    if field_is_default {
+                    if *field_is_default {
                         quote!(
                             #field_name: core::default::Default::default()
                         )
@@ -65,6 +69,29 @@ pub(crate) fn expand_borrow(
                     }
                 });
 
+            let init_locals = fields
+                .iter()
+                .filter(|(_, field_is_default)| !*field_is_default)
+                .map(|((_, field_type), _)| {
+                    quote!(
+                        <#field_type as ::shipyard::Borrow>::init_locals(locals);
+                    )
+                });
+
+            let field_with_locals = fields
+                .iter()
+                .map(|((field_name, field_type), field_is_default)| {
+                    if *field_is_default {
+                        quote!(
+                            #field_name: core::default::Default::default()
+                        )
+                    } else {
+                        quote!(
+                            #field_name: <#field_type as ::shipyard::Borrow>::borrow_with_locals(all_storages, all_borrow.clone(), locals, last_run, current)?
+                        )
+                    }
+                });
+
             Ok(quote!(
                 impl #impl_generics ::shipyard::Borrow for #name #ty_generics #where_clause {
                     type View<'__view> = #name #gat_ty_generics;
@@ -74,6 +101,16 @@ pub(crate) fn expand_borrow(
                             #(#field),*
                         })
                     }
+
+                    fn init_locals(locals: &mut ::shipyard::SystemLocals) {
+                        #(#init_locals)*
+                    }
+
+                    fn borrow_with_locals<'__a>(all_storages: & '__a ::shipyard::AllStorages, all_borrow: Option<::shipyard::SharedBorrow<'__a>>, locals: &mut ::shipyard::SystemLocalsCursor<'__a>, last_run: Option<u32>, current: u32,) -> Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
+                        Ok(#name {
+                            #(#field_with_locals),*
+                        })
+                    }
                 }
             ))
         }
@@ -86,6 +123,16 @@ pub(crate) fn expand_borrow(
                     quote!(<#field_type as ::shipyard::Borrow>::borrow(all_storages, all_borrow.clone(), last_run, current)?)
                 });
 
+            let init_locals = fields.unnamed.iter().map(|field| {
+                let field_type = &field.ty;
+                quote!(<#field_type as ::shipyard::Borrow>::init_locals(locals);)
+            });
+
+            let borrow_with_locals = fields.unnamed.iter().map(|field| {
+                let field_type = &field.ty;
+                quote!(<#field_type as ::shipyard::Borrow>::borrow_with_locals(all_storages, all_borrow.clone(), locals, last_run, current)?)
+            });
+
             Ok(quote!(
                 impl #impl_generics ::shipyard::Borrow for #name #ty_generics #where_clause {
                     type View<'__view> = #name #gat_ty_generics;
@@ -93,6 +140,14 @@ pub(crate) fn expand_borrow(
                     fn borrow<'__a>(all_storages: & '__a ::shipyard::AllStorages, all_borrow: Option<::shipyard::SharedBorrow<'__a>>, last_run: Option<u32>, current: u32) -> Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
                         Ok(#name(#(#borrow),*))
                     }
+
+                    fn init_locals(locals: &mut ::shipyard::SystemLocals) {
+                        #(#init_locals)*
+                    }
+
+                    fn borrow_with_locals<'__a>(all_storages: & '__a ::shipyard::AllStorages, all_borrow: Option<::shipyard::SharedBorrow<'__a>>, locals: &mut ::shipyard::SystemLocalsCursor<'__a>, last_run: Option<u32>, current: u32) -> Result<Self::View<'__a>, ::shipyard::error::GetStorage> {
+                        Ok(#name(#(#borrow_with_locals),*))
+                    }
                 }
             ))
         }
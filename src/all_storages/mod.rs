@@ -1,31 +1,39 @@
 mod custom_storage;
 mod delete_any;
 mod retain;
+mod untyped_query;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
 pub use retain::TupleRetain;
+pub use untyped_query::{UntypedComponents, UntypedIter, UntypedQuery};
 
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::Borrow;
-use crate::component::Unique;
+use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::get_component::GetComponent;
 use crate::iter_component::{IntoIterRef, IterComponent};
+use crate::listener::ListenerId;
 use crate::memory_usage::AllStoragesMemoryUsage;
+use crate::name::Name;
+use crate::prefab::Prefab;
 use crate::public_transport::RwLock;
 use crate::public_transport::ShipyardRwLock;
 use crate::reserve::BulkEntityIter;
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
 use crate::storage::{SBox, Storage, StorageId};
 use crate::system::AllSystem;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::views::EntitiesViewMut;
+use crate::views::{EntitiesViewMut, ViewMut};
 use crate::{error, UniqueStorage};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::type_name;
+use core::ops::Range;
 use core::sync::atomic::AtomicU32;
 use hashbrown::hash_map::{Entry, HashMap};
 
@@ -42,6 +50,9 @@ pub struct AllStorages {
     #[cfg(feature = "thread_local")]
     thread_id: std::thread::ThreadId,
     counter: Arc<AtomicU32>,
+    /// Number of event updates, used to expire events.
+    event_updates: AtomicU32,
+    prefabs: HashMap<Cow<'static, str>, Arc<Prefab>>,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -61,6 +72,8 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id: std::thread::current().id(),
             counter,
+            event_updates: AtomicU32::new(0),
+            prefabs: HashMap::new(),
         }
     }
     pub(crate) fn new_with_lock<L: ShipyardRwLock + Send + Sync>(counter: Arc<AtomicU32>) -> Self {
@@ -73,6 +86,8 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id: std::thread::current().id(),
             counter,
+            event_updates: AtomicU32::new(0),
+            prefabs: HashMap::new(),
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -285,6 +300,122 @@ impl AllStorages {
             unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
         }
     }
+    /// Returns the [`StorageId`] of all storages with a component for `entity`.\
+    /// Storages that can't be borrowed are skipped, use [`components_of_mut`](AllStorages::components_of_mut) to visit all of them.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared), one at a time
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    /// all_storages.add_entity((USIZE(1),));
+    ///
+    /// let storages = all_storages.components_of(entity).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+    /// ```
+    pub fn components_of(&self, entity: EntityId) -> impl Iterator<Item = StorageId> {
+        let storages = self.storages.read();
+
+        storages
+            .iter()
+            .filter_map(|(&storage_id, storage)| {
+                let storage = unsafe { &*storage.0 }.borrow().ok()?;
+
+                if storage.contains(entity) {
+                    Some(storage_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+    /// Returns the [`StorageId`] of all storages with a component for `entity`.\
+    /// Unlike [`components_of`](AllStorages::components_of), no storage is borrowed so none can be skipped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    ///
+    /// let storages = all_storages.components_of_mut(entity).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+    /// ```
+    pub fn components_of_mut(&mut self, entity: EntityId) -> impl Iterator<Item = StorageId> {
+        self.storages
+            .get_mut()
+            .iter_mut()
+            .filter_map(|(&storage_id, storage)| {
+                if unsafe { &mut *storage.0 }.get_mut().contains(entity) {
+                    Some(storage_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+    /// Returns the name of the storage identified by `storage_id`, if present.
+    ///
+    /// ### Borrows
+    ///
+    /// - Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    ///
+    /// let storage_id = all_storages.components_of(entity).next().unwrap();
+    ///
+    /// assert!(all_storages.storage_name(storage_id).unwrap().contains("U32"));
+    /// ```
+    #[track_caller]
+    pub fn storage_name(&self, storage_id: StorageId) -> Option<Cow<'static, str>> {
+        let storages = self.storages.read();
+        let storage = storages.get(&storage_id)?;
+
+        match unsafe { &*storage.0 }.borrow() {
+            Ok(storage) => Some(storage.name()),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
     /// Deletes all components of an entity except the ones passed in `S`.  
     /// The storage's type has to be used and not the component.  
     /// `SparseSet` is the default storage.
@@ -940,7 +1071,8 @@ let i = all_storages.run(sys1);
         .unwrap()
     }
     /// Make the given entity alive.  
-    /// Does nothing if an entity with a greater generation is already at this index.  
+    /// Does nothing if an entity with a greater generation is already at this index
+    /// or if the index is part of the [local range](Entities::set_local_range).  
     /// Returns `true` if the entity is successfully spawned.
     #[inline]
     pub fn spawn(&mut self, entity: EntityId) -> bool {
@@ -948,10 +1080,227 @@ let i = all_storages.run(sys1);
             .unwrap()
             .spawn(entity)
     }
+    /// Reserves `range` for locally created entities.  
+    /// See [`Entities::set_local_range`] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is empty or too large to fit in an `EntityId`.
+    #[inline]
+    #[track_caller]
+    pub fn set_local_entity_range(&mut self, range: Range<u64>) {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .set_local_range(range);
+    }
+    /// Removes the local entity range, new entities can use any index again.
+    #[inline]
+    pub fn remove_local_entity_range(&mut self) {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .remove_local_range();
+    }
+    /// Registers `prefab` under `name`, replacing any prefab with the same name.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// all_storages.add_prefab("u32", prefab);
+    /// ```
+    pub fn add_prefab(&mut self, name: impl Into<Cow<'static, str>>, prefab: Prefab) {
+        self.prefabs.insert(name.into(), Arc::new(prefab));
+    }
+    /// Returns `true` if a prefab is registered under `name`.
+    pub fn contains_prefab(&self, name: &str) -> bool {
+        self.prefabs.contains_key(name)
+    }
+    /// Creates new entities from the prefab registered under `name` and returns the `EntityId` of its root.
+    ///
+    /// ### Errors
+    ///
+    /// - No prefab is registered under `name`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// all_storages.add_prefab("u32", prefab);
+    ///
+    /// let entity = all_storages.spawn_prefab("u32").unwrap();
+    /// ```
+    pub fn spawn_prefab(&mut self, name: &str) -> Result<EntityId, error::SpawnPrefab> {
+        let prefab = self
+            .prefabs
+            .get(name)
+            .cloned()
+            .ok_or(error::SpawnPrefab::MissingPrefab)?;
+
+        let current = self.get_current();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let new_entities = (0..prefab.entity_count())
+            .map(|_| entities.generate())
+            .collect::<Vec<_>>();
+
+        prefab.spawn_into(self, &new_entities, current);
+
+        Ok(new_entities[0])
+    }
+    /// Creates `count` copies of the prefab registered under `name`.\
+    /// Returns an iterator yielding the `EntityId` of each copy's root.
+    ///
+    /// ### Errors
+    ///
+    /// - No prefab is registered under `name`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// all_storages.add_prefab("u32", prefab);
+    ///
+    /// let new_entities = all_storages.bulk_spawn_prefab("u32", 10).unwrap();
+    /// assert_eq!(new_entities.len(), 10);
+    /// ```
+    pub fn bulk_spawn_prefab(
+        &mut self,
+        name: &str,
+        count: usize,
+    ) -> Result<BulkEntityIter<'_>, error::SpawnPrefab> {
+        let prefab = self
+            .prefabs
+            .get(name)
+            .cloned()
+            .ok_or(error::SpawnPrefab::MissingPrefab)?;
+
+        let current = self.get_current();
+
+        // copies of the same prefab entity are contiguous, roots come first
+        let new_entities = self
+            .bulk_add_entity((0..count * prefab.entity_count()).map(|_| ()))
+            .as_slice()
+            .to_vec();
+
+        if count > 0 {
+            prefab.bulk_spawn_into(self, &new_entities, count, current);
+        }
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let roots_start = new_entities.first().map_or(0, |root| root.uindex());
+
+        Ok(BulkEntityIter {
+            iter: entities.data[roots_start..roots_start + count]
+                .iter()
+                .copied(),
+            slice: &entities.data[roots_start..roots_start + count],
+        })
+    }
     /// Displays storages memory information.
     pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
         AllStoragesMemoryUsage(self)
     }
+    /// Creates a query over storages only known at runtime.\
+    /// It matches the entities with a component in all `include` storages and none in the `exclude` storages.
+    ///
+    /// An included storage that doesn't exist matches no entity, an excluded one is ignored.
+    ///
+    /// ### Borrows
+    ///
+    /// - All `include` and `exclude` storages (shared) until the query is dropped
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity((Position(0.0),));
+    /// all_storages.add_entity((Position(1.0), Frozen));
+    ///
+    /// let query = all_storages
+    ///     .untyped_query(
+    ///         &[StorageId::of::<SparseSet<Position>>()],
+    ///         &[StorageId::of::<SparseSet<Frozen>>()],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// for (_, components) in query.iter() {
+    ///     let position = components.get(0).unwrap().downcast_ref::<Position>().unwrap();
+    ///
+    ///     assert_eq!(position.0, 0.0);
+    /// }
+    /// ```
+    pub fn untyped_query(
+        &self,
+        include: &[StorageId],
+        exclude: &[StorageId],
+    ) -> Result<UntypedQuery<'_>, error::GetStorage> {
+        let mut query = UntypedQuery {
+            include: Vec::with_capacity(include.len()),
+            exclude: Vec::with_capacity(exclude.len()),
+            is_empty: false,
+        };
+
+        for &storage_id in include {
+            match self.custom_storage_by_id(storage_id) {
+                Ok(storage) => query.include.push(storage),
+                Err(error::GetStorage::MissingStorage { .. }) => query.is_empty = true,
+                Err(err) => return Err(err),
+            }
+        }
+
+        for &storage_id in exclude {
+            match self.custom_storage_by_id(storage_id) {
+                Ok(storage) => query.exclude.push(storage),
+                Err(error::GetStorage::MissingStorage { .. }) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(query)
+    }
 
     #[inline]
     pub(crate) fn get_current(&self) -> u32 {
@@ -959,6 +1308,36 @@ let i = all_storages.run(sys1);
             .fetch_add(1, core::sync::atomic::Ordering::Acquire)
     }
 
+    /// Returns the number of event updates.
+    pub(crate) fn event_updates(&self) -> u32 {
+        self.event_updates
+            .load(core::sync::atomic::Ordering::Acquire)
+    }
+    /// Starts a new event update, events sent before the previous update expire.\
+    /// Each workload run already starts one when it ends, this is only needed to expire events outside of workloads.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, EventWriter, World};
+    ///
+    /// struct Collision(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|all_storages: AllStoragesViewMut| {
+    ///     all_storages.run(|mut collisions: EventWriter<Collision>| {
+    ///         collisions.send(Collision(0));
+    ///     });
+    ///
+    ///     all_storages.update_events();
+    /// });
+    /// ```
+    #[inline]
+    pub fn update_events(&self) {
+        self.event_updates
+            .fetch_add(1, core::sync::atomic::Ordering::AcqRel);
+    }
+
     /// Returns a timestamp used to clear tracking information.
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
         TrackingTimestamp(self.counter.load(core::sync::atomic::Ordering::Acquire))
@@ -1065,6 +1444,45 @@ assert!(*j == &U32(1));
 
         T::get(self, None, current, entity)
     }
+    /// Returns the first entity with the given [`Name`].\
+    /// Names are not indexed, this is a linear search.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`Name`] storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`Name`] storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Name, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let player = all_storages.add_entity((Name::new("player_ship"),));
+    ///
+    /// assert_eq!(all_storages.entity_by_name("player_ship"), Some(player));
+    /// assert_eq!(all_storages.entity_by_name("enemy_ship"), None);
+    /// ```
+    #[track_caller]
+    pub fn entity_by_name(&self, name: &str) -> Option<EntityId> {
+        match self.custom_storage::<SparseSet<Name>>() {
+            Ok(names) => names.entity_by_name(name),
+            Err(error::GetStorage::MissingStorage { .. }) => None,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+    /// Calls `f` with the [`Name`] storage.\
+    /// `f` gets `None` when the storage doesn't exist or is exclusively borrowed.
+    pub(crate) fn with_names<R>(&self, f: impl FnOnce(Option<&SparseSet<Name>>) -> R) -> R {
+        match self.custom_storage::<SparseSet<Name>>() {
+            Ok(names) => f(Some(&names)),
+            Err(_) => f(None),
+        }
+    }
 
     #[doc = "Iterate components.
 
@@ -1141,7 +1559,8 @@ for (i, j) in &mut iter {
         }
     }
 
-    /// Sets the on entity deletion callback.
+    /// Registers a callback called each time an entity is created.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
     ///
     /// ### Borrows
     ///
@@ -1151,10 +1570,114 @@ for (i, j) in &mut iter {
     ///
     /// - Entities borrow failed.
     #[track_caller]
-    pub fn on_deletion(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) {
+    pub fn on_creation(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
         let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
 
-        entities.on_deletion(f);
+        entities.on_creation(f)
+    }
+    /// Registers a callback called each time an entity is deleted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn on_deletion(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.on_deletion(f)
+    }
+    /// Registers a callback called each time an entity is made alive with `spawn`.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn on_spawn(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.on_spawn(f)
+    }
+    /// Unregisters an entity creation, deletion or spawn callback.\
+    /// Returns `true` if the callback was registered.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn remove_entity_listener(&self, id: ListenerId) -> bool {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.remove_listener(id)
+    }
+    /// Registers a callback called each time a `T` component is inserted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_component_listener`](Self::remove_component_listener).\
+    /// See [`SparseSet::on_insert`](crate::SparseSet::on_insert) for more details.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn on_insert<T: Send + Sync + Component>(
+        &self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) -> ListenerId {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.on_insert(f)
+    }
+    /// Registers a callback called each time a `T` component is removed or deleted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_component_listener`](Self::remove_component_listener).\
+    /// See [`SparseSet::on_remove`](crate::SparseSet::on_remove) for more details.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn on_remove<T: Send + Sync + Component>(
+        &self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) -> ListenerId {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.on_remove(f)
+    }
+    /// Unregisters a `T` component insertion or removal callback.\
+    /// Returns `true` if the callback was registered.
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn remove_component_listener<T: Send + Sync + Component>(&self, id: ListenerId) -> bool {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.remove_listener(id)
     }
 }
 
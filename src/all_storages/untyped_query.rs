@@ -0,0 +1,155 @@
+use crate::atomic_refcell::ARef;
+use crate::entity_id::EntityId;
+use crate::storage::Storage;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::slice;
+
+/// Query over storages only known at runtime by their [`StorageId`].
+///
+/// Created with [`AllStorages::untyped_query`].\
+/// The storages are borrowed as long as the query lives.
+///
+/// [`StorageId`]: crate::StorageId
+/// [`AllStorages::untyped_query`]: crate::AllStorages::untyped_query
+pub struct UntypedQuery<'a> {
+    pub(super) include: Vec<ARef<'a, &'a dyn Storage>>,
+    pub(super) exclude: Vec<ARef<'a, &'a dyn Storage>>,
+    /// One of the included storages doesn't exist, no entity can match.
+    pub(super) is_empty: bool,
+}
+
+impl<'a> UntypedQuery<'a> {
+    /// Iterates the entities with a component in all included storages and none in the excluded ones.\
+    /// The iteration follows the smallest included storage.
+    pub fn iter(&self) -> UntypedIter<'_> {
+        let mut entities: &[EntityId] = &[];
+
+        if !self.is_empty {
+            entities = self
+                .include
+                .iter()
+                .filter_map(|storage| storage.dense_entities())
+                .min_by_key(|dense| dense.len())
+                .unwrap_or(&[]);
+        }
+
+        UntypedIter {
+            include: &self.include,
+            exclude: &self.exclude,
+            entities: entities.iter(),
+        }
+    }
+    /// Returns `true` if `entity` matches the query.
+    pub fn contains(&self, entity: EntityId) -> bool {
+        !self.is_empty && matches(&self.include, &self.exclude, entity)
+    }
+    /// Returns the components of `entity` if it matches the query.
+    pub fn get(&self, entity: EntityId) -> Option<UntypedComponents<'_>> {
+        if self.contains(entity) {
+            Some(UntypedComponents {
+                include: &self.include,
+                entity,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the entities matching an [`UntypedQuery`].
+pub struct UntypedIter<'q> {
+    include: &'q [ARef<'q, &'q dyn Storage>],
+    exclude: &'q [ARef<'q, &'q dyn Storage>],
+    entities: slice::Iter<'q, EntityId>,
+}
+
+impl<'q> Iterator for UntypedIter<'q> {
+    type Item = (EntityId, UntypedComponents<'q>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &entity in self.entities.by_ref() {
+            if matches(self.include, self.exclude, entity) {
+                return Some((
+                    entity,
+                    UntypedComponents {
+                        include: self.include,
+                        entity,
+                    },
+                ));
+            }
+        }
+
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.entities.size_hint().1)
+    }
+}
+
+/// Components of an entity matching an [`UntypedQuery`], in the order of the included storages.
+#[derive(Clone, Copy)]
+pub struct UntypedComponents<'q> {
+    include: &'q [ARef<'q, &'q dyn Storage>],
+    entity: EntityId,
+}
+
+impl<'q> UntypedComponents<'q> {
+    /// Returns the entity owning the components.
+    pub fn id(&self) -> EntityId {
+        self.entity
+    }
+    /// Returns the number of included storages.
+    pub fn len(&self) -> usize {
+        self.include.len()
+    }
+    /// Returns `true` if the query didn't include any storage.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty()
+    }
+    /// Returns the component from the `index`th included storage.\
+    /// Returns `None` if `index` is out of bounds or the storage isn't a [`SparseSet`].
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    pub fn get(&self, index: usize) -> Option<&'q dyn Any> {
+        let storage: &'q dyn Storage = &**self.include.get(index)?;
+
+        storage.any_at(dense_index(storage, self.entity)?)
+    }
+    /// Iterates the components in the order of the included storages.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'q dyn Any>> + 'q {
+        let components = *self;
+
+        (0..self.len()).map(move |index| components.get(index))
+    }
+}
+
+/// Returns the index of `entity`'s component using the storage's [`SparseArray`](crate::sparse_set::SparseArray).
+fn dense_index(storage: &dyn Storage, entity: EntityId) -> Option<usize> {
+    storage
+        .sparse_array()?
+        .get(entity)
+        .filter(|sparse_entity| sparse_entity.gen() == entity.gen())
+        .map(|sparse_entity| sparse_entity.uindex())
+}
+
+fn has_component(storage: &dyn Storage, entity: EntityId) -> bool {
+    if storage.sparse_array().is_some() {
+        dense_index(storage, entity).is_some()
+    } else {
+        storage.contains(entity)
+    }
+}
+
+fn matches(
+    include: &[ARef<'_, &'_ dyn Storage>],
+    exclude: &[ARef<'_, &'_ dyn Storage>],
+    entity: EntityId,
+) -> bool {
+    include
+        .iter()
+        .all(|storage| has_component(&**storage, entity))
+        && !exclude
+            .iter()
+            .any(|storage| has_component(&**storage, entity))
+}
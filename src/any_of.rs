@@ -0,0 +1,61 @@
+/// Yield the entities that have at least one of the components.
+///
+/// Works with up to 10 storages, each matched storage yields `Some`, the others `None`.\
+/// Entities are yielded in the order of the first storage then the entities missing from the previous storages of the second storage, and so on.
+///
+/// Like [`Or`], an `AnyOf` filter never drives iteration when used with other storages.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AnyOf, Component, IntoIter, View, World};
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct A(u32);
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct B(u32);
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct C(u32);
+///
+/// let mut world = World::new();
+///
+/// world.add_entity((A(0), B(0)));
+/// world.add_entity((B(1),));
+/// world.add_entity((C(2),));
+/// world.add_entity((B(3), C(3)));
+///
+/// let (a, b, c) = world.borrow::<(View<A>, View<B>, View<C>)>().unwrap();
+///
+/// assert_eq!(
+///     AnyOf::new((&a, &b, &c)).iter().collect::<Vec<_>>(),
+///     vec![
+///         (Some(&A(0)), Some(&B(0)), None),
+///         (None, Some(&B(1)), None),
+///         (None, Some(&B(3)), Some(&C(3))),
+///         (None, None, Some(&C(2))),
+///     ]
+/// );
+/// ```
+///
+/// [`Or`]: crate::Or
+#[derive(Copy, Clone)]
+pub struct AnyOf<T>(pub(crate) T);
+
+impl<T> AnyOf<T> {
+    /// Creates a filter yielding the entities with at least one component in `views`.
+    pub fn new(views: T) -> AnyOf<T> {
+        AnyOf(views)
+    }
+}
+
+/// Index of an entity's components in each storage of an [`AnyOf`] filter.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct AnyOfIndex<T>(pub(crate) T);
+
+impl<T> From<usize> for AnyOfIndex<T> {
+    fn from(_: usize) -> Self {
+        unreachable!()
+    }
+}
@@ -9,14 +9,15 @@ use crate::all_storages::{AllStorages, CustomStorageAccess};
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::error;
+use crate::event::Events;
 use crate::scheduler::TypeInfo;
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
 use crate::tracking::{Track, Tracking};
 use crate::unique::UniqueStorage;
 use crate::views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, EventReader, EventWriter,
+    UniqueView, UniqueViewMut, View, ViewMut,
 };
 use alloc::vec::Vec;
 use core::any::type_name;
@@ -337,6 +338,30 @@ where
     }
 }
 
+unsafe impl<'a, E: Send + Sync + 'static> BorrowInfo for EventReader<'a, E> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Events<E>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<Events<E>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+unsafe impl<'a, E: Send + Sync + 'static> BorrowInfo for EventWriter<'a, E> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<Events<E>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<Events<E>>(),
+            thread_safe: true,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
 unsafe impl<'a, T: Send + Sync + Unique> BorrowInfo for UniqueView<'a, T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
         info.push(TypeInfo {
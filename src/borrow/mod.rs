@@ -20,10 +20,14 @@ use crate::all_storages::{AllStorages, CustomStorageAccess};
 use crate::atomic_refcell::{ARef, ARefMut, SharedBorrow};
 use crate::component::{Component, Unique};
 use crate::error;
+use crate::event::Events;
 use crate::sparse_set::SparseSet;
 use crate::tracking::{Track, Tracking};
 use crate::unique::UniqueStorage;
-use crate::views::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
+use crate::views::{
+    EntitiesView, EntitiesViewMut, EventCursor, EventReader, EventWriter, Local, SystemLocals,
+    SystemLocalsCursor, UniqueView, UniqueViewMut, View, ViewMut,
+};
 use core::marker::PhantomData;
 
 /// Describes if a storage is borrowed exclusively or not.  
@@ -84,6 +88,22 @@ pub trait Borrow {
         last_run: Option<u32>,
         current: u32,
     ) -> Result<Self::View<'a>, error::GetStorage>;
+    /// Creates the [`Local`](crate::Local) states of a system when it's added to a workload.
+    #[doc(hidden)]
+    #[inline]
+    fn init_locals(_locals: &mut SystemLocals) {}
+    /// Same as [`borrow`](Borrow::borrow) with access to the system's [`Local`](crate::Local) states.
+    #[doc(hidden)]
+    #[inline]
+    fn borrow_with_locals<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _locals: &mut SystemLocalsCursor<'a>,
+        last_run: Option<u32>,
+        current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Self::borrow(all_storages, all_borrow, last_run, current)
+    }
 }
 
 impl Borrow for () {
@@ -173,6 +193,7 @@ where
                 .unwrap_or_else(|| current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             borrow,
             all_borrow,
             phantom: PhantomData,
@@ -206,6 +227,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             borrow,
             all_borrow,
             phantom: PhantomData,
@@ -239,6 +261,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             borrow,
             all_borrow,
             phantom: PhantomData,
@@ -272,6 +295,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             borrow,
             all_borrow,
             phantom: PhantomData,
@@ -305,6 +329,7 @@ where
                 .unwrap_or_else(|| current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             _borrow: borrow,
             _all_borrow: all_borrow,
             phantom: PhantomData,
@@ -338,6 +363,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             _borrow: borrow,
             _all_borrow: all_borrow,
             phantom: PhantomData,
@@ -371,6 +397,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             _borrow: borrow,
             _all_borrow: all_borrow,
             phantom: PhantomData,
@@ -404,6 +431,7 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current.wrapping_sub(u32::MAX / 2)),
             current,
             sparse_set,
+            all_storages,
             _borrow: borrow,
             _all_borrow: all_borrow,
             phantom: PhantomData,
@@ -539,6 +567,88 @@ impl<T: Send + Sync + Unique> Borrow for UniqueViewMut<'_, T> {
     }
 }
 
+impl<E: Send + Sync + 'static> Borrow for EventWriter<'_, E> {
+    type View<'a> = EventWriter<'a, E>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<u32>,
+        _current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let run = all_storages.event_updates();
+        let view = all_storages.custom_storage_or_insert_mut(|| Events::<E>::new(run))?;
+
+        let (events, borrow) = unsafe { ARefMut::destructure(view) };
+
+        events.update(run);
+
+        Ok(EventWriter {
+            events,
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+impl<E: Send + Sync + 'static> Borrow for EventReader<'_, E> {
+    type View<'a> = EventReader<'a, E>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<u32>,
+        current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let cursor = Local::<EventCursor<E>>::borrow(all_storages, None, last_run, current)?;
+
+        event_reader(all_storages, all_borrow, cursor)
+    }
+    #[inline]
+    fn init_locals(locals: &mut SystemLocals) {
+        Local::<EventCursor<E>>::init_locals(locals);
+    }
+    #[inline]
+    fn borrow_with_locals<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        locals: &mut SystemLocalsCursor<'a>,
+        last_run: Option<u32>,
+        current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let cursor = Local::<EventCursor<E>>::borrow_with_locals(
+            all_storages,
+            None,
+            locals,
+            last_run,
+            current,
+        )?;
+
+        event_reader(all_storages, all_borrow, cursor)
+    }
+}
+
+fn event_reader<'a, E: Send + Sync + 'static>(
+    all_storages: &'a AllStorages,
+    all_borrow: Option<SharedBorrow<'a>>,
+    cursor: Local<'a, EventCursor<E>>,
+) -> Result<EventReader<'a, E>, error::GetStorage> {
+    let run = all_storages.event_updates();
+    let view = all_storages.custom_storage_or_insert(|| Events::<E>::new(run))?;
+
+    let (events, borrow) = unsafe { ARef::destructure(view) };
+
+    Ok(EventReader {
+        events,
+        cursor,
+        run,
+        _borrow: borrow,
+        _all_borrow: all_borrow,
+    })
+}
+
 #[cfg(feature = "thread_local")]
 impl<T: Sync + Unique> Borrow for NonSend<UniqueViewMut<'_, T>> {
     type View<'a> = NonSend<UniqueViewMut<'a, T>>;
@@ -645,6 +755,24 @@ macro_rules! impl_borrow {
             ) -> Result<Self::View<'a>, error::GetStorage> {
                 Ok(($($type::borrow(all_storages, all_borrow.clone(), last_run, current)?,)+))
             }
+
+            #[inline]
+            fn init_locals(locals: &mut SystemLocals) {
+                $(
+                    $type::init_locals(locals);
+                )+
+            }
+
+            #[inline]
+            fn borrow_with_locals<'a>(
+                all_storages: &'a AllStorages,
+                all_borrow: Option<SharedBorrow<'a>>,
+                locals: &mut SystemLocalsCursor<'a>,
+                last_run: Option<u32>,
+                current: u32
+            ) -> Result<Self::View<'a>, error::GetStorage> {
+                Ok(($($type::borrow_with_locals(all_storages, all_borrow.clone(), locals, last_run, current)?,)+))
+            }
         }
     }
 }
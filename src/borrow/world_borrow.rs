@@ -1,7 +1,7 @@
 use crate::atomic_refcell::ARef;
 use crate::borrow::Borrow;
 use crate::error;
-use crate::views::{AllStoragesView, AllStoragesViewMut};
+use crate::views::{AllStoragesView, AllStoragesViewMut, SystemLocals, SystemLocalsCursor};
 use crate::world::World;
 
 /// Allows a type to be borrowed by [`World::borrow`], [`World::run`] and workloads.
@@ -15,6 +15,21 @@ pub trait WorldBorrow {
         last_run: Option<u32>,
         current: u32,
     ) -> Result<Self::WorldView<'_>, error::GetStorage>;
+    /// Creates the [`Local`](crate::Local) states of a system when it's added to a workload.
+    #[doc(hidden)]
+    #[inline]
+    fn world_init_locals(_locals: &mut SystemLocals) {}
+    /// Same as [`world_borrow`](WorldBorrow::world_borrow) with access to the system's [`Local`](crate::Local) states.
+    #[doc(hidden)]
+    #[inline]
+    fn world_borrow_with_locals<'a>(
+        world: &'a World,
+        _locals: &mut SystemLocalsCursor<'a>,
+        last_run: Option<u32>,
+        current: u32,
+    ) -> Result<Self::WorldView<'a>, error::GetStorage> {
+        Self::world_borrow(world, last_run, current)
+    }
 }
 
 impl<T: Borrow> WorldBorrow for T {
@@ -36,6 +51,27 @@ impl<T: Borrow> WorldBorrow for T {
 
         T::borrow(all_storages, Some(all_borrow), last_run, current)
     }
+    #[inline]
+    fn world_init_locals(locals: &mut SystemLocals) {
+        T::init_locals(locals);
+    }
+    fn world_borrow_with_locals<'a>(
+        world: &'a World,
+        locals: &mut SystemLocalsCursor<'a>,
+        last_run: Option<u32>,
+        current: u32,
+    ) -> Result<Self::WorldView<'a>, error::GetStorage> {
+        let (all_storages, all_borrow) = unsafe {
+            ARef::destructure(
+                world
+                    .all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?,
+            )
+        };
+
+        T::borrow_with_locals(all_storages, Some(all_borrow), locals, last_run, current)
+    }
 }
 
 impl WorldBorrow for AllStoragesView<'_> {
@@ -7,12 +7,15 @@ use crate::add_distinct_component::AddDistinctComponent;
 use crate::add_entity::AddEntity;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::listener::{ListenerId, Listeners};
 use crate::memory_usage::StorageMemoryUsage;
 use crate::reserve::{BulkEntityIter, BulkReserve};
 use crate::storage::Storage;
 use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 use core::iter::repeat_with;
+use core::ops::Range;
 
 /// Entities holds the EntityIds to all entities: living, removed and dead.
 ///
@@ -35,16 +38,35 @@ use core::iter::repeat_with;
 pub struct Entities {
     pub(crate) data: Vec<EntityId>,
     list: Option<(usize, usize)>,
-    on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
+    on_creation: Listeners<EntityListener>,
+    on_deletion: Listeners<EntityListener>,
+    on_spawn: Listeners<EntityListener>,
+    next_listener_id: u64,
+    local_range: Option<LocalRange>,
 }
 
+/// Index range reserved for locally created entities.
+struct LocalRange {
+    range: Range<u64>,
+    /// Indices in `next..range.end` were never used locally and aren't part of the shared list.
+    next: u64,
+    /// Local indices below `next` available for reuse.
+    free: VecDeque<u64>,
+}
+
+type EntityListener = dyn FnMut(EntityId) + Send + Sync;
+
 impl Entities {
     #[inline]
     pub(crate) fn new() -> Self {
         Entities {
             data: Vec::new(),
             list: None,
-            on_deletion: None,
+            on_creation: Listeners::new(),
+            on_deletion: Listeners::new(),
+            on_spawn: Listeners::new(),
+            next_listener_id: 0,
+            local_range: None,
         }
     }
     /// Returns `true` if `entity` matches a living entity.
@@ -133,6 +155,35 @@ impl Entities {
         }
     }
     pub(crate) fn generate(&mut self) -> EntityId {
+        let entity_id = self.private_generate();
+
+        for listener in self.on_creation.iter_mut() {
+            (listener)(entity_id);
+        }
+
+        entity_id
+    }
+    fn private_generate(&mut self) -> EntityId {
+        if let Some(local_range) = &mut self.local_range {
+            if let Some(index) = local_range.free.pop_front() {
+                // SAFE local indices below next are always in bound
+                unsafe {
+                    self.data.get_unchecked_mut(index as usize).set_index(index);
+                    return *self.data.get_unchecked(index as usize);
+                }
+            }
+
+            let index = local_range.next;
+            assert!(
+                index < local_range.range.end,
+                "Local entity range {:?} is exhausted.",
+                local_range.range
+            );
+            local_range.next += 1;
+
+            return self.claim(index);
+        }
+
         if let Some((new, ref mut old)) = self.list {
             let old_index = *old;
 
@@ -155,11 +206,152 @@ impl Entities {
             entity_id
         }
     }
-    pub(crate) fn bulk_generate(&mut self, count: usize) -> &[EntityId] {
-        self.data
-            .extend((self.data.len() as u64..(self.data.len() + count) as u64).map(EntityId::new));
+    /// Generates `count` entities with contiguous indices and returns their position in `data`.
+    pub(crate) fn bulk_generate(&mut self, count: usize) -> Range<usize> {
+        let start = if let Some(local_range) = &mut self.local_range {
+            let start = local_range.next;
+            assert!(
+                start + count as u64 <= local_range.range.end,
+                "Local entity range {:?} is exhausted.",
+                local_range.range
+            );
+            local_range.next += count as u64;
+
+            for index in start..start + count as u64 {
+                self.claim(index);
+            }
+
+            start as usize
+        } else {
+            let start = self.data.len();
+
+            self.data
+                .extend((start as u64..(start + count) as u64).map(EntityId::new));
+
+            start
+        };
+
+        let new_entities = &self.data[start..start + count];
+
+        for listener in self.on_creation.iter_mut() {
+            for &entity_id in new_entities {
+                (listener)(entity_id);
+            }
+        }
+
+        start..start + count
+    }
+    /// Makes the unused local index alive.
+    fn claim(&mut self, index: u64) -> EntityId {
+        if let Some(entity_id) = self.data.get_mut(index as usize) {
+            // unused local indices are never part of the shared list
+            entity_id.set_index(index);
+
+            *entity_id
+        } else {
+            let entity_id = EntityId::new(index);
+
+            let is_spawned = self.private_spawn(entity_id);
+            assert!(is_spawned, "Local index {} is already in use.", index);
+
+            entity_id
+        }
+    }
+    /// Takes all indices in `range` out of the shared list in a single pass and returns them.
+    fn unlink_range(&mut self, range: &Range<u64>) -> VecDeque<u64> {
+        let mut unlinked = VecDeque::new();
+
+        let (new, old) = match self.list {
+            Some(list) => list,
+            None => return unlinked,
+        };
+
+        let mut kept: Option<(usize, usize)> = None;
+        let mut current = old;
+        loop {
+            let next = self.data[current].uindex();
+
+            if range.contains(&(current as u64)) {
+                self.data[current].set_index(EntityId::max_index());
+                unlinked.push_back(current as u64);
+            } else if let Some((kept_new, _)) = &mut kept {
+                self.data[*kept_new].set_index(current as u64);
+                *kept_new = current;
+            } else {
+                kept = Some((current, current));
+            }
+
+            if current == new {
+                break;
+            }
+
+            current = next;
+        }
+
+        if let Some((kept_new, _)) = kept {
+            self.data[kept_new].set_index(EntityId::max_index());
+        }
+
+        self.list = kept;
+
+        unlinked
+    }
+    /// Grows `data` up to the end of the local range.\
+    /// The new indices outside the local range are added to the shared list, the others are left unused.
+    fn pad_local_range(&mut self) {
+        let range = match &self.local_range {
+            Some(local_range) => local_range.range.clone(),
+            None => return,
+        };
+
+        let old_len = self.data.len();
+        if old_len >= range.end as usize {
+            return;
+        }
+
+        self.data.resize(range.end as usize, EntityId::new(0));
+
+        for index in old_len as u64..range.end {
+            if index < range.start {
+                self.push_free(index);
+            } else {
+                self.data[index as usize].set_index(EntityId::max_index());
+            }
+        }
+    }
+    /// Adds a deleted entity's index to the list of indices available for reuse.
+    fn push_free(&mut self, index: u64) {
+        if let Some(local_range) = &mut self.local_range {
+            if local_range.range.contains(&index) {
+                // SAFE index is always in bound
+                unsafe {
+                    self.data
+                        .get_unchecked_mut(index as usize)
+                        .set_index(EntityId::max_index())
+                };
+                local_range.free.push_back(index);
+
+                return;
+            }
+        }
 
-        &self.data[self.data.len() - count..self.data.len()]
+        if let Some((ref mut new, _)) = self.list {
+            // SAFE new and index are always in bound
+            unsafe { self.data.get_unchecked_mut(*new).set_index(index) };
+            unsafe {
+                self.data
+                    .get_unchecked_mut(index as usize)
+                    .set_index(EntityId::max_index())
+            };
+            *new = index as usize;
+        } else {
+            unsafe {
+                self.data
+                    .get_unchecked_mut(index as usize)
+                    .set_index(EntityId::max_index())
+            };
+            self.list = Some((index as usize, index as usize));
+        }
     }
     /// Deletes an entity, returns true if the entity was alive.  
     /// If the entity has components, they will not be deleted and still be accessible using this id.
@@ -172,31 +364,11 @@ impl Entities {
                     .bump_gen()
                     .is_ok()
             } {
-                if let Some((ref mut new, _)) = self.list {
-                    // SAFE new is always in bound
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(*new)
-                            .set_index(entity_id.index())
-                    };
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(entity_id.uindex())
-                            .set_index(EntityId::max_index())
-                    };
-                    *new = entity_id.uindex();
-                } else {
-                    unsafe {
-                        self.data
-                            .get_unchecked_mut(entity_id.uindex())
-                            .set_index(EntityId::max_index())
-                    };
-                    self.list = Some((entity_id.uindex(), entity_id.uindex()));
-                }
+                self.push_free(entity_id.index());
             }
 
-            if let Some(on_deletion) = &mut self.on_deletion {
-                (on_deletion)(entity_id)
+            for listener in self.on_deletion.iter_mut() {
+                (listener)(entity_id);
             }
 
             true
@@ -272,22 +444,28 @@ impl Entities {
         let mut iter = component.into_iter();
         let len = iter.size_hint().0;
 
-        let entities_len = self.data.len();
         let new_entities = self.bulk_generate(len);
+        let start = new_entities.start;
+        let mut end = new_entities.end;
 
-        storages.bulk_reserve(new_entities);
-        for (component, id) in (&mut iter).zip(new_entities.iter().copied()) {
+        storages.bulk_reserve(&self.data[new_entities.clone()]);
+        for (component, id) in (&mut iter).zip(self.data[new_entities].iter().copied()) {
             AddEntity::add_entity(&mut storages, id, component);
         }
 
         // have to use two loops because of self borrow
-        for (component, id) in iter.zip(repeat_with(|| self.generate())) {
+        // entities are generated one by one to stay contiguous with the ones above
+        for (component, id) in iter.zip(repeat_with(|| {
+            let index = self.bulk_generate(1).start;
+            end = index + 1;
+            self.data[index]
+        })) {
             AddEntity::add_entity(&mut storages, id, component);
         }
 
         BulkEntityIter {
-            iter: self.data[entities_len..].iter().copied(),
-            slice: &self.data[entities_len..],
+            iter: self.data[start..end].iter().copied(),
+            slice: &self.data[start..end],
         }
     }
     /// Creates an iterator over all entities.
@@ -296,9 +474,32 @@ impl Entities {
         self.into_iter()
     }
     /// Make the given entity alive.  
-    /// Does nothing if an entity with a greater generation is already at this index.  
+    /// Does nothing if an entity with a greater generation is already at this index
+    /// or if the index is part of the [local range](Entities::set_local_range).  
     /// Returns `true` if the entity is successfully spawned.
     pub fn spawn(&mut self, entity: EntityId) -> bool {
+        if let Some(local_range) = &self.local_range {
+            if local_range.range.contains(&entity.index()) {
+                return false;
+            }
+
+            // the local range has to stay out of the shared list
+            if entity.index() >= local_range.range.end {
+                self.pad_local_range();
+            }
+        }
+
+        if self.private_spawn(entity) {
+            for listener in self.on_spawn.iter_mut() {
+                (listener)(entity);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+    fn private_spawn(&mut self, entity: EntityId) -> bool {
         if let Some(&old_entity) = self.data.get(entity.index() as usize) {
             if self.is_alive(old_entity) {
                 if old_entity.gen() <= entity.gen() {
@@ -374,9 +575,91 @@ impl Entities {
         }
     }
 
-    /// Sets the on entity deletion callback.
-    pub fn on_deletion(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) {
-        self.on_deletion = Some(Box::new(f));
+    /// Reserves `range` for locally created entities.\
+    /// New entities will only use indices in this range while [`spawn`](Entities::spawn) refuses them.
+    /// This lets ids decided elsewhere, by a server for example, be spawned outside of it without conflicts.
+    ///
+    /// Alive entities in `range` are kept and their indices will be reused once deleted.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is empty or too large to fit in an `EntityId`.
+    /// - Later, when creating an entity while all indices in `range` are used.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{EntitiesViewMut, EntityId, World};
+    ///
+    /// let world = World::new();
+    /// let mut entities = world.borrow::<EntitiesViewMut>().unwrap();
+    ///
+    /// entities.set_local_range(1_000_000..2_000_000);
+    ///
+    /// let local = entities.add_entity((), ());
+    /// assert_eq!(local.index(), 1_000_000);
+    ///
+    /// let remote = EntityId::new_from_index_and_gen(0, 0);
+    /// assert!(entities.spawn(remote));
+    /// assert!(!entities.spawn(EntityId::new_from_index_and_gen(1_000_001, 0)));
+    /// ```
+    #[track_caller]
+    pub fn set_local_range(&mut self, range: Range<u64>) {
+        assert!(
+            range.start < range.end && range.end <= EntityId::max_index(),
+            "Invalid local entity range {:?}.",
+            range
+        );
+
+        self.remove_local_range();
+
+        // indices past the end of data are claimed in order, the others are reused from the free list
+        let free = self.unlink_range(&range);
+        let next = (self.data.len() as u64).clamp(range.start, range.end);
+
+        self.local_range = Some(LocalRange { range, next, free });
+    }
+    /// Returns the [local range](Entities::set_local_range), if any.
+    #[inline]
+    pub fn local_range(&self) -> Option<Range<u64>> {
+        self.local_range
+            .as_ref()
+            .map(|local_range| local_range.range.clone())
+    }
+    /// Removes the [local range](Entities::set_local_range), new entities can use any index again.
+    pub fn remove_local_range(&mut self) {
+        if let Some(local_range) = self.local_range.take() {
+            for index in local_range.free {
+                self.push_free(index);
+            }
+
+            // unused indices added by pad_local_range
+            for index in local_range.next..local_range.range.end.min(self.data.len() as u64) {
+                self.push_free(index);
+            }
+        }
+    }
+    /// Registers a callback called each time an entity is created.\
+    /// Returns an id that can be used to unregister the callback with [`Entities::remove_listener`].
+    pub fn on_creation(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        self.on_creation
+            .push(&mut self.next_listener_id, Box::new(f))
+    }
+    /// Registers a callback called each time an entity is deleted.\
+    /// Returns an id that can be used to unregister the callback with [`Entities::remove_listener`].
+    pub fn on_deletion(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        self.on_deletion
+            .push(&mut self.next_listener_id, Box::new(f))
+    }
+    /// Registers a callback called each time an entity is made alive with [`Entities::spawn`].\
+    /// Returns an id that can be used to unregister the callback with [`Entities::remove_listener`].
+    pub fn on_spawn(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        self.on_spawn.push(&mut self.next_listener_id, Box::new(f))
+    }
+    /// Unregisters a creation, deletion or spawn callback.\
+    /// Returns `true` if the callback was registered.
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.on_creation.remove(id) || self.on_deletion.remove(id) || self.on_spawn.remove(id)
     }
 }
 
@@ -394,8 +677,8 @@ impl Storage for Entities {
             if id.bump_gen().is_ok() {
                 last_alive = i as u64;
 
-                if let Some(on_deletion) = &mut self.on_deletion {
-                    (on_deletion)(id_before_bump)
+                for listener in self.on_deletion.iter_mut() {
+                    (listener)(id_before_bump);
                 }
             }
 
@@ -414,6 +697,11 @@ impl Storage for Entities {
             .position(|id| id.gen() < EntityId::max_gen())
             .unwrap();
         self.list = Some((self.data.len() - end - 1, begin));
+
+        // all indices are back in the shared list, local ones have to be taken out again
+        if let Some(local_range) = self.local_range.take() {
+            self.set_local_range(local_range.range);
+        }
     }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(StorageMemoryUsage {
@@ -1,11 +1,14 @@
 //! All error types.
 
 use crate::info::TypeInfo;
+use crate::name::{EntityLabel, Name};
 use crate::scheduler::Label;
+use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
 use crate::{entity_id::EntityId, tracking::tracking_fmt};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::{Debug, Display, Formatter};
 #[cfg(feature = "std")]
@@ -292,6 +295,8 @@ pub enum AddWorkload {
         id: StorageId,
         borrow: Borrow,
     },
+    /// A workload with a fixed timestep was nested in another workload.
+    NestedFixedTimestep(Box<dyn Label>),
 }
 
 // For some reason this trait can't be derived with Box<dyn Label>
@@ -322,6 +327,9 @@ impl PartialEq for AddWorkload {
                     borrow: r_borrow,
                 },
             ) => l_name == r_name && l_id == r_id && l_borrow == r_borrow,
+            (AddWorkload::NestedFixedTimestep(l0), AddWorkload::NestedFixedTimestep(r0)) => {
+                l0 == r0
+            }
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -355,6 +363,10 @@ impl Debug for AddWorkload {
             AddWorkload::TrackingAllStoragesBorrow => f.write_str(
                 "Cannot immutably borrow AllStorages while it's already mutably borrowed.",
             ),
+            AddWorkload::NestedFixedTimestep(workload) => f.write_fmt(format_args!(
+                "Workload({:?}) has a fixed timestep and can't be nested in another workload.",
+                workload
+            )),
             AddWorkload::TrackingStorageBorrow { name, id, borrow } => {
                 if let Some(name) = name {
                     match borrow {
@@ -417,6 +429,38 @@ impl Display for SetDefaultWorkload {
     }
 }
 
+/// Error returned by [`World::set_system_enabled`] and [`ScheduledWorkload::set_system_enabled`].
+///
+/// [`World::set_system_enabled`]: crate::World::set_system_enabled()
+/// [`ScheduledWorkload::set_system_enabled`]: crate::ScheduledWorkload::set_system_enabled()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SetSystemEnabled {
+    /// The `Scheduler` is exclusively borrowed.
+    Borrow,
+    /// No system with this label exists.
+    MissingSystem,
+}
+
+#[cfg(feature = "std")]
+impl Error for SetSystemEnabled {}
+
+impl Debug for SetSystemEnabled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SetSystemEnabled::Borrow => {
+                f.write_str("Cannot borrow the scheduler while it's already mutably borrowed.")
+            }
+            SetSystemEnabled::MissingSystem => f.write_str("No system with this label exists."),
+        }
+    }
+}
+
+impl Display for SetSystemEnabled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`run_default`] and [`run_workload`].
 /// The error can be a storage error, problem with the scheduler's borrowing, a non existent workload or a custom error.
 ///
@@ -429,6 +473,10 @@ pub enum RunWorkload {
     Run((Box<dyn Label>, Run)),
     /// Workload is not present in the world.
     MissingWorkload,
+    /// A system panicked while its workload had a [`PanicPolicy`], contains the panic message.
+    ///
+    /// [`PanicPolicy`]: crate::PanicPolicy
+    Panic((Box<dyn Label>, String)),
 }
 
 impl RunWorkload {
@@ -463,6 +511,9 @@ impl Debug for RunWorkload {
             RunWorkload::Run((system_name, run)) => {
                 f.write_fmt(format_args!("System {:?} failed: {:?}", system_name, run))
             }
+            RunWorkload::Panic((system_name, message)) => {
+                f.write_fmt(format_args!("System {:?} panicked: {}", system_name, message))
+            }
         }
     }
 }
@@ -550,6 +601,35 @@ pub struct MissingComponent {
     pub name: &'static str,
 }
 
+impl MissingComponent {
+    /// Returns a value printing this error with the entity's [`Name`](crate::Name), if it has one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Get, Name, View, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let player = world.add_entity((Name::new("player_ship"),));
+    ///
+    /// world.run(|healths: View<Health>, names: View<Name>| {
+    ///     let err = healths.get(player).unwrap_err();
+    ///
+    ///     assert!(format!("{}", err.with_names(&names)).starts_with("player_ship ("));
+    /// });
+    /// ```
+    pub fn with_names(self, names: &SparseSet<Name>) -> NamedMissingComponent<'_> {
+        NamedMissingComponent {
+            error: self,
+            entity_name: names.name_of(self.id),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for MissingComponent {}
 
@@ -568,6 +648,49 @@ impl Display for MissingComponent {
     }
 }
 
+/// [`MissingComponent`] printed with the entity's [`Name`](crate::Name).
+///
+/// Returned by [`MissingComponent::with_names`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NamedMissingComponent<'a> {
+    error: MissingComponent,
+    entity_name: Option<&'a str>,
+}
+
+impl NamedMissingComponent<'_> {
+    /// Returns the error without the entity's name.
+    pub fn error(&self) -> MissingComponent {
+        self.error
+    }
+    /// Returns the entity's name, if it has one.
+    pub fn entity_name(&self) -> Option<&str> {
+        self.entity_name
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NamedMissingComponent<'_> {}
+
+impl Debug for NamedMissingComponent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let label = EntityLabel {
+            entity: self.error.id,
+            name: self.entity_name,
+        };
+
+        f.write_fmt(format_args!(
+            "{:?} does not have a {} component.",
+            label, self.error.name
+        ))
+    }
+}
+
+impl Display for NamedMissingComponent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Returned when trying to add an invalid system to a workload.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InvalidSystem {
@@ -857,3 +980,68 @@ impl Display for GetComponent {
         Debug::fmt(self, f)
     }
 }
+
+/// Returned by [`ViewMut::get_many_mut`].
+///
+/// [`ViewMut::get_many_mut`]: crate::ViewMut::get_many_mut
+#[derive(Clone, PartialEq, Eq)]
+pub enum GetMany {
+    /// The same entity was requested multiple times.
+    DuplicateId(EntityId),
+    #[allow(missing_docs)]
+    MissingComponent(MissingComponent),
+}
+
+impl From<MissingComponent> for GetMany {
+    fn from(missing_component: MissingComponent) -> GetMany {
+        GetMany::MissingComponent(missing_component)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for GetMany {}
+
+impl Debug for GetMany {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            GetMany::DuplicateId(id) => f.write_fmt(format_args!(
+                "Entity {:?} was requested multiple times.",
+                id
+            )),
+            GetMany::MissingComponent(err) => f.write_fmt(format_args!("{:?}", err)),
+        }
+    }
+}
+
+impl Display for GetMany {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`World::spawn_prefab`] and [`AllStorages::spawn_prefab`].
+///
+/// [`World::spawn_prefab`]: crate::World::spawn_prefab()
+/// [`AllStorages::spawn_prefab`]: crate::AllStorages::spawn_prefab()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SpawnPrefab {
+    /// No prefab is registered under this name.
+    MissingPrefab,
+}
+
+#[cfg(feature = "std")]
+impl Error for SpawnPrefab {}
+
+impl Debug for SpawnPrefab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SpawnPrefab::MissingPrefab => f.write_str("No prefab with this name exists. You first need to add the prefab using `World::add_prefab`."),
+        }
+    }
+}
+
+impl Display for SpawnPrefab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
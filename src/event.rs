@@ -0,0 +1,128 @@
+use crate::memory_usage::StorageMemoryUsage;
+use crate::storage::Storage;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::mem::size_of;
+
+/// Event storage.
+///
+/// Events are kept in two buffers, the events sent during the current workload run and the ones sent during the previous run.\
+/// An event can be read until the end of the workload run following the one it was sent in.\
+/// Outside of workloads, [`World::update_events`] can be used to expire events manually.
+///
+/// The storage is created by the first [`EventWriter`] or [`EventReader`] borrow.
+///
+/// [`EventWriter`]: crate::EventWriter
+/// [`EventReader`]: crate::EventReader
+/// [`World::update_events`]: crate::World::update_events
+pub struct Events<E: Send + Sync + 'static> {
+    older: EventBuffer<E>,
+    newer: EventBuffer<E>,
+    event_count: usize,
+}
+
+/// Events sent during a single update.
+struct EventBuffer<E> {
+    events: Vec<E>,
+    /// Id of the first event of the buffer.
+    start: usize,
+    /// Update the events were sent in.
+    run: u32,
+}
+
+impl<E> EventBuffer<E> {
+    fn new(start: usize, run: u32) -> EventBuffer<E> {
+        EventBuffer {
+            events: Vec::new(),
+            start,
+            run,
+        }
+    }
+    /// Returns `true` if the events are still readable during `run`.
+    fn is_live(&self, run: u32) -> bool {
+        run.wrapping_sub(self.run) < 2
+    }
+    /// Returns the events with an id greater or equal to `cursor`.
+    fn events_from(&self, cursor: usize) -> &[E] {
+        let skip = cursor.saturating_sub(self.start).min(self.events.len());
+
+        &self.events[skip..]
+    }
+}
+
+impl<E: Send + Sync + 'static> Events<E> {
+    pub(crate) fn new(run: u32) -> Events<E> {
+        Events {
+            older: EventBuffer::new(0, run.wrapping_sub(1)),
+            newer: EventBuffer::new(0, run),
+            event_count: 0,
+        }
+    }
+    /// Swaps the buffers if a new update started, expired events are dropped.
+    pub(crate) fn update(&mut self, run: u32) {
+        match run.wrapping_sub(self.newer.run) {
+            0 => {}
+            1 => {
+                core::mem::swap(&mut self.older, &mut self.newer);
+                self.newer.events.clear();
+                self.newer.start = self.event_count;
+                self.newer.run = run;
+            }
+            _ => {
+                self.older.events.clear();
+                self.older.start = self.event_count;
+                self.older.run = run.wrapping_sub(1);
+                self.newer.events.clear();
+                self.newer.start = self.event_count;
+                self.newer.run = run;
+            }
+        }
+    }
+    /// Adds an event to the current buffer.
+    pub(crate) fn send(&mut self, event: E) {
+        self.newer.events.push(event);
+        self.event_count += 1;
+    }
+    /// Id the next event will get.
+    pub(crate) fn event_count(&self) -> usize {
+        self.event_count
+    }
+    /// Returns the events readable during `run` with an id greater or equal to `cursor`, oldest first.
+    pub(crate) fn events_from(&self, cursor: usize, run: u32) -> (&[E], &[E]) {
+        let older = if self.older.is_live(run) {
+            self.older.events_from(cursor)
+        } else {
+            &[]
+        };
+        let newer = if self.newer.is_live(run) {
+            self.newer.events_from(cursor)
+        } else {
+            &[]
+        };
+
+        (older, newer)
+    }
+}
+
+impl<E: Send + Sync + 'static> Storage for Events<E> {
+    fn clear(&mut self, _current: u32) {
+        self.older.events.clear();
+        self.older.start = self.event_count;
+        self.newer.events.clear();
+        self.newer.start = self.event_count;
+    }
+    fn memory_usage(&self) -> Option<StorageMemoryUsage> {
+        Some(StorageMemoryUsage {
+            storage_name: type_name::<Self>().into(),
+            allocated_memory_bytes: (self.older.events.capacity() + self.newer.events.capacity())
+                * size_of::<E>()
+                + size_of::<Self>(),
+            used_memory_bytes: (self.older.events.len() + self.newer.events.len()) * size_of::<E>()
+                + size_of::<Self>(),
+            component_count: self.older.events.len() + self.newer.events.len(),
+        })
+    }
+    fn is_empty(&self) -> bool {
+        self.older.events.is_empty() && self.newer.events.is_empty()
+    }
+}
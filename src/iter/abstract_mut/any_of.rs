@@ -0,0 +1,87 @@
+use super::AbstractMut;
+use crate::any_of::{AnyOf, AnyOfIndex};
+use crate::entity_id::EntityId;
+
+macro_rules! impl_abstract_mut_any_of {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: AbstractMut),+> AbstractMut for AnyOf<($($type,)+)> {
+            type Out = ($(Option<$type::Out>,)+);
+            type Index = AnyOfIndex<($(Option<$type::Index>,)+)>;
+
+            #[inline]
+            unsafe fn get_data(&self, _: usize) -> Self::Out {
+                unreachable!()
+            }
+            #[inline]
+            unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
+                ($(index.0.$index.map(|index| (self.0).$index.get_datas(index)),)+)
+            }
+            #[inline]
+            #[allow(unused_assignments)]
+            fn indices_of(&self, entity: EntityId, index: usize, mask: u16) -> Option<Self::Index> {
+                let indices = ($((self.0).$index.indices_of(entity, index, mask),)+);
+
+                // `index` goes through the dense of each storage one after the other
+                // the entity is only yielded from the first storage it is part of
+                let mut start = 0;
+                let mut in_previous = false;
+
+                $(
+                    let len = (self.0).$index.len();
+
+                    if index < start + len {
+                        return if in_previous || indices.$index.is_none() {
+                            None
+                        } else {
+                            Some(AnyOfIndex(indices))
+                        };
+                    }
+
+                    in_previous |= indices.$index.is_some();
+                    start += len;
+                )+
+
+                None
+            }
+            #[inline]
+            fn indices_of_passenger(
+                &self,
+                entity: EntityId,
+                index: usize,
+                mask: u16,
+            ) -> Option<Self::Index> {
+                let indices = ($((self.0).$index.indices_of(entity, index, mask),)+);
+
+                if $(indices.$index.is_some())||+ {
+                    Some(AnyOfIndex(indices))
+                } else {
+                    None
+                }
+            }
+            #[inline]
+            unsafe fn indices_of_unchecked(&self, _: EntityId, _: usize, _: u16) -> Self::Index {
+                unreachable!()
+            }
+            #[inline]
+            unsafe fn get_id(&self, _: usize) -> EntityId {
+                unreachable!()
+            }
+            #[inline]
+            fn len(&self) -> usize {
+                0
+            }
+        }
+    }
+}
+
+macro_rules! abstract_mut_any_of {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_abstract_mut_any_of![$(($type, $index))*];
+        abstract_mut_any_of![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_abstract_mut_any_of![$(($type, $index))*];
+    }
+}
+
+abstract_mut_any_of![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,55 @@
+use super::AbstractMut;
+use crate::entity_id::EntityId;
+use crate::tracking::{Changed, ChangedIndex};
+
+macro_rules! impl_abstract_mut_changed {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: AbstractMut),+> AbstractMut for Changed<($($type,)+)> {
+            type Out = ($($type::Out,)+);
+            type Index = ChangedIndex<($($type::Index,)+)>;
+
+            #[inline]
+            unsafe fn get_data(&self, _: usize) -> Self::Out {
+                unreachable!()
+            }
+            #[inline]
+            unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
+                ($(self.views.$index.get_datas(index.0.$index),)+)
+            }
+            #[inline]
+            fn indices_of(&self, entity: EntityId, _: usize, _: u16) -> Option<Self::Index> {
+                let indices = ($(self.views.$index.indices_of_passenger(entity, 0, 0)?,)+);
+
+                if $(self.views.$index.is_changed(indices.$index.clone(), self.since))||+ {
+                    Some(ChangedIndex(indices))
+                } else {
+                    None
+                }
+            }
+            #[inline]
+            unsafe fn indices_of_unchecked(&self, _: EntityId, _: usize, _: u16) -> Self::Index {
+                unreachable!()
+            }
+            #[inline]
+            unsafe fn get_id(&self, _: usize) -> EntityId {
+                unreachable!()
+            }
+            #[inline]
+            fn len(&self) -> usize {
+                0
+            }
+        }
+    }
+}
+
+macro_rules! abstract_mut_changed {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_abstract_mut_changed![$(($type, $index))*];
+        abstract_mut_changed![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_abstract_mut_changed![$(($type, $index))*];
+    }
+}
+
+abstract_mut_changed![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
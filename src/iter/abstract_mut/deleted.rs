@@ -0,0 +1,36 @@
+use super::AbstractMut;
+use crate::entity_id::EntityId;
+use crate::tracking::Deleted;
+
+impl<'a, T> AbstractMut for Deleted<'a, T> {
+    type Out = &'a T;
+    type Index = usize;
+
+    #[inline]
+    unsafe fn get_data(&self, index: usize) -> Self::Out {
+        &self
+            .deletion_data
+            .get_unchecked(*self.indices.get_unchecked(index))
+            .2
+    }
+    #[inline]
+    unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
+        self.get_data(index)
+    }
+    #[inline]
+    fn indices_of(&self, entity_id: EntityId, _: usize, _: u16) -> Option<Self::Index> {
+        self.entities.binary_search(&entity_id).ok()
+    }
+    #[inline]
+    unsafe fn indices_of_unchecked(&self, entity_id: EntityId, _: usize, _: u16) -> Self::Index {
+        self.entities.binary_search(&entity_id).unwrap_unchecked()
+    }
+    #[inline]
+    unsafe fn get_id(&self, index: usize) -> EntityId {
+        *self.entities.get_unchecked(index)
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.entities.len()
+    }
+}
@@ -1,13 +1,19 @@
+mod any_of;
+mod changed;
+mod deleted;
 mod inserted;
 mod inserted_or_modified;
 mod modified;
 mod not;
+mod optional;
 mod or;
+mod removed;
 
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::r#mut::Mut;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut};
+use crate::tracking::is_track_within_bounds;
 
 #[allow(missing_docs)]
 #[allow(clippy::len_without_is_empty)]
@@ -42,6 +48,13 @@ pub trait AbstractMut {
     unsafe fn get_id(&self, index: usize) -> EntityId;
     #[doc(hidden)]
     fn len(&self) -> usize;
+    /// Returns `true` if the component at `index` was inserted or modified.\
+    /// When `since` is `Some`, compares to this timestamp instead of the last run.
+    #[inline]
+    #[doc(hidden)]
+    fn is_changed(&self, _index: Self::Index, _since: Option<u32>) -> bool {
+        false
+    }
 }
 
 impl<'tmp, T: Component> AbstractMut for FullRawWindow<'tmp, T> {
@@ -72,6 +85,28 @@ impl<'tmp, T: Component> AbstractMut for FullRawWindow<'tmp, T> {
     fn len(&self) -> usize {
         self.dense_len
     }
+    #[inline]
+    fn is_changed(&self, index: Self::Index, since: Option<u32>) -> bool {
+        let (last_insertion, last_modification) = match since {
+            Some(since) => (since.wrapping_sub(1), since.wrapping_sub(1)),
+            None => (self.last_insertion, self.last_modification),
+        };
+
+        unsafe {
+            (self.is_tracking_insertion
+                && is_track_within_bounds(
+                    *self.insertion_data.add(index),
+                    last_insertion,
+                    self.current,
+                ))
+                || (self.is_tracking_modification
+                    && is_track_within_bounds(
+                        *self.modification_data.add(index),
+                        last_modification,
+                        self.current,
+                    ))
+        }
+    }
 }
 
 impl<'tmp, T: Component> AbstractMut for FullRawWindowMut<'tmp, T> {
@@ -114,6 +149,28 @@ impl<'tmp, T: Component> AbstractMut for FullRawWindowMut<'tmp, T> {
     fn len(&self) -> usize {
         self.dense_len
     }
+    #[inline]
+    fn is_changed(&self, index: Self::Index, since: Option<u32>) -> bool {
+        let (last_insertion, last_modification) = match since {
+            Some(since) => (since.wrapping_sub(1), since.wrapping_sub(1)),
+            None => (self.last_insertion, self.last_modification),
+        };
+
+        unsafe {
+            (self.is_tracking_insertion
+                && is_track_within_bounds(
+                    *self.insertion_data.add(index),
+                    last_insertion,
+                    self.current,
+                ))
+                || (self.is_tracking_modification
+                    && is_track_within_bounds(
+                        *self.modification_data.add(index),
+                        last_modification,
+                        self.current,
+                    ))
+        }
+    }
 }
 
 macro_rules! impl_abstract_mut {
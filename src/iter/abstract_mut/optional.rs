@@ -0,0 +1,47 @@
+use super::AbstractMut;
+use crate::entity_id::EntityId;
+use crate::optional::Optional;
+
+impl<Storage: AbstractMut<Index = usize>> AbstractMut for Optional<Storage> {
+    type Out = Option<Storage::Out>;
+    type Index = Option<usize>;
+
+    #[inline]
+    unsafe fn get_data(&self, index: usize) -> Self::Out {
+        Some(self.0.get_data(index))
+    }
+    #[inline]
+    unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
+        index.map(|index| self.0.get_datas(index))
+    }
+    #[inline]
+    fn indices_of(&self, entity_id: EntityId, index: usize, mask: u16) -> Option<Self::Index> {
+        Some(self.0.indices_of(entity_id, index, mask))
+    }
+    #[inline]
+    fn indices_of_passenger(
+        &self,
+        entity_id: EntityId,
+        index: usize,
+        mask: u16,
+    ) -> Option<Self::Index> {
+        Some(self.0.indices_of_passenger(entity_id, index, mask))
+    }
+    #[inline]
+    unsafe fn indices_of_unchecked(
+        &self,
+        entity_id: EntityId,
+        index: usize,
+        mask: u16,
+    ) -> Self::Index {
+        self.0.indices_of(entity_id, index, mask)
+    }
+    #[inline]
+    unsafe fn get_id(&self, index: usize) -> EntityId {
+        self.0.get_id(index)
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
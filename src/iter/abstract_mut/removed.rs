@@ -0,0 +1,29 @@
+use super::AbstractMut;
+use crate::entity_id::EntityId;
+use crate::tracking::Removed;
+
+impl<T> AbstractMut for Removed<'_, T> {
+    type Out = ();
+    type Index = usize;
+
+    #[inline]
+    unsafe fn get_data(&self, _: usize) -> Self::Out {}
+    #[inline]
+    unsafe fn get_datas(&self, _: Self::Index) -> Self::Out {}
+    #[inline]
+    fn indices_of(&self, entity_id: EntityId, _: usize, _: u16) -> Option<Self::Index> {
+        self.entities.binary_search(&entity_id).ok()
+    }
+    #[inline]
+    unsafe fn indices_of_unchecked(&self, entity_id: EntityId, _: usize, _: u16) -> Self::Index {
+        self.entities.binary_search(&entity_id).unwrap_unchecked()
+    }
+    #[inline]
+    unsafe fn get_id(&self, index: usize) -> EntityId {
+        *self.entities.get_unchecked(index)
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.entities.len()
+    }
+}
@@ -0,0 +1,300 @@
+use super::abstract_mut::AbstractMut;
+use super::into_abstract::IntoAbstract;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::{FullRawWindow, FullRawWindowMut};
+use core::slice;
+
+/// Iterator over a storage's entities and components in contiguous slices.
+///
+/// Use [`IntoChunks::iter_chunks`] to iterate multiple storages in chunks.\
+/// Created with [`View::iter_chunks`] or [`ViewMut::iter_chunks`].
+///
+/// [`View::iter_chunks`]: crate::View::iter_chunks
+/// [`ViewMut::iter_chunks`]: crate::ViewMut::iter_chunks
+pub struct Chunks<'a, T> {
+    pub(crate) entities: slice::Chunks<'a, EntityId>,
+    pub(crate) data: slice::Chunks<'a, T>,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = (&'a [EntityId], &'a [T]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.entities.next()?, self.data.next()?))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+/// Iterator over a storage's entities and mutable components in contiguous slices.
+///
+/// All components of a chunk are flagged as modified when the chunk is yielded.\
+/// Created with [`ViewMut::iter_chunks_mut`].
+///
+/// [`ViewMut::iter_chunks_mut`]: crate::ViewMut::iter_chunks_mut
+pub struct ChunksMut<'a, T> {
+    pub(crate) entities: slice::Chunks<'a, EntityId>,
+    pub(crate) data: slice::ChunksMut<'a, T>,
+    pub(crate) modification_data: Option<slice::ChunksMut<'a, u32>>,
+    pub(crate) current: u32,
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = (&'a [EntityId], &'a mut [T]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let entities = self.entities.next()?;
+        let data = self.data.next()?;
+
+        if let Some(modification_data) = &mut self.modification_data {
+            if let Some(flags) = modification_data.next() {
+                flags.fill(self.current);
+            }
+        }
+
+        Some((entities, data))
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksMut<'_, T> {}
+
+/// Storage that can be sliced, used by [`GroupedChunks`].
+#[allow(missing_docs)]
+pub trait AbstractChunk: AbstractMut {
+    type Entities;
+    type Slice;
+
+    #[doc(hidden)]
+    unsafe fn get_entities(&self, start: Self::Index, len: usize) -> Self::Entities;
+    #[doc(hidden)]
+    unsafe fn get_slice(&self, start: Self::Index, len: usize) -> Self::Slice;
+    /// Returns `true` if `next` directly follows `previous` in all storages.
+    #[doc(hidden)]
+    fn is_next(previous: &Self::Index, next: &Self::Index) -> bool;
+}
+
+impl<'tmp, T: Component> AbstractChunk for FullRawWindow<'tmp, T> {
+    type Entities = &'tmp [EntityId];
+    type Slice = &'tmp [T];
+
+    #[inline]
+    unsafe fn get_entities(&self, start: Self::Index, len: usize) -> Self::Entities {
+        slice::from_raw_parts(self.dense.add(start), len)
+    }
+    #[inline]
+    unsafe fn get_slice(&self, start: Self::Index, len: usize) -> Self::Slice {
+        slice::from_raw_parts(self.data.add(start), len)
+    }
+    #[inline]
+    fn is_next(previous: &Self::Index, next: &Self::Index) -> bool {
+        *next == *previous + 1
+    }
+}
+
+impl<'tmp, T: Component> AbstractChunk for FullRawWindowMut<'tmp, T> {
+    type Entities = &'tmp [EntityId];
+    type Slice = &'tmp mut [T];
+
+    #[inline]
+    unsafe fn get_entities(&self, start: Self::Index, len: usize) -> Self::Entities {
+        slice::from_raw_parts(self.dense.add(start), len)
+    }
+    #[inline]
+    unsafe fn get_slice(&self, start: Self::Index, len: usize) -> Self::Slice {
+        if self.is_tracking_modification {
+            slice::from_raw_parts_mut(self.modification_data.add(start), len).fill(self.current);
+        }
+
+        slice::from_raw_parts_mut(self.data.add(start), len)
+    }
+    #[inline]
+    fn is_next(previous: &Self::Index, next: &Self::Index) -> bool {
+        *next == *previous + 1
+    }
+}
+
+/// Iterator over multiple storages' entities and components in contiguous slices.
+///
+/// The smallest storage drives the iteration, entities missing from another storage are skipped.\
+/// A chunk ends after `chunk_size` elements or when the next entity doesn't directly follow the previous one in all storages,
+/// so chunks can be shorter than `chunk_size`.\
+/// Mutable components of a chunk are flagged as modified when the chunk is yielded.
+///
+/// Created with [`IntoChunks::iter_chunks`].
+pub struct GroupedChunks<Storage> {
+    pub(crate) storage: Storage,
+    pub(crate) dense: &'static [EntityId],
+    pub(crate) current: usize,
+    pub(crate) mask: u16,
+    pub(crate) chunk_size: usize,
+}
+
+impl<Storage: AbstractChunk> Iterator for GroupedChunks<Storage> {
+    type Item = (Storage::Entities, Storage::Slice);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&entity) = self.dense.get(self.current) {
+            let start = match self.storage.indices_of(entity, self.current, self.mask) {
+                Some(start) => start,
+                None => {
+                    self.current += 1;
+                    continue;
+                }
+            };
+
+            let mut previous = start.clone();
+            let mut len = 1;
+
+            while len < self.chunk_size {
+                let index = self.current + len;
+                let next = match self.dense.get(index) {
+                    Some(&entity) => self.storage.indices_of(entity, index, self.mask),
+                    None => None,
+                };
+
+                match next {
+                    Some(next) if Storage::is_next(&previous, &next) => {
+                        previous = next;
+                        len += 1;
+                    }
+                    _ => break,
+                }
+            }
+
+            self.current += len;
+
+            return Some(unsafe {
+                (
+                    self.storage.get_entities(start.clone(), len),
+                    self.storage.get_slice(start, len),
+                )
+            });
+        }
+
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.dense.len() - self.current))
+    }
+}
+
+/// Trait used to iterate multiple storages in contiguous slices.
+pub trait IntoChunks {
+    #[allow(missing_docs)]
+    type Storage: AbstractChunk;
+
+    /// Iterates the entities and components of these storages in slices of at most `chunk_size` elements.\
+    /// See [`GroupedChunks`] for how chunks are split.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoChunks, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..10).map(|i| (Position(i as f32), Velocity(1.0))));
+    ///
+    /// let (mut positions, velocities) = world
+    ///     .borrow::<(ViewMut<Position>, View<Velocity>)>()
+    ///     .unwrap();
+    ///
+    /// for (_, (positions, velocities)) in (&mut positions, &velocities).iter_chunks(4) {
+    ///     for (position, velocity) in positions.iter_mut().zip(velocities) {
+    ///         position.0 += velocity.0;
+    ///     }
+    /// }
+    /// ```
+    fn iter_chunks(self, chunk_size: usize) -> GroupedChunks<Self::Storage>;
+}
+
+macro_rules! impl_into_chunks {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: AbstractChunk<Index = usize>),+> AbstractChunk for ($($type,)+) {
+            type Entities = A::Entities;
+            type Slice = ($($type::Slice,)+);
+
+            #[inline]
+            unsafe fn get_entities(&self, start: Self::Index, len: usize) -> Self::Entities {
+                self.0.get_entities(start.0, len)
+            }
+            #[inline]
+            unsafe fn get_slice(&self, start: Self::Index, len: usize) -> Self::Slice {
+                ($(self.$index.get_slice(start.$index, len),)+)
+            }
+            #[inline]
+            fn is_next(previous: &Self::Index, next: &Self::Index) -> bool {
+                $($type::is_next(&previous.$index, &next.$index))&&+
+            }
+        }
+
+        impl<$($type: IntoAbstract),+> IntoChunks for ($($type,)+) where $($type::AbsView: AbstractChunk<Index = usize>),+ {
+            type Storage = ($($type::AbsView,)+);
+
+            fn iter_chunks(self, chunk_size: usize) -> GroupedChunks<Self::Storage> {
+                assert!(chunk_size != 0, "chunk size must be non-zero");
+
+                let mut smallest = usize::MAX;
+                let mut smallest_dense = core::ptr::null();
+                let mut mask: u16 = 0;
+
+                $(
+                    if let Some(len) = self.$index.len() {
+                        if len < smallest {
+                            smallest = len;
+                            smallest_dense = self.$index.dense();
+                            mask = 1 << $index;
+                        }
+                    }
+                )+
+
+                let dense = if smallest == usize::MAX {
+                    &[]
+                } else {
+                    unsafe { slice::from_raw_parts(smallest_dense, smallest) }
+                };
+
+                GroupedChunks {
+                    storage: ($(self.$index.into_abstract(),)+),
+                    dense,
+                    current: 0,
+                    mask,
+                    chunk_size,
+                }
+            }
+        }
+    }
+}
+
+macro_rules! into_chunks {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_chunks![$(($type, $index))*];
+        into_chunks![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_into_chunks![$(($type, $index))*];
+    }
+}
+
+into_chunks![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
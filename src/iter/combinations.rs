@@ -0,0 +1,159 @@
+use super::abstract_mut::AbstractMut;
+use super::iter::Iter;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::r#mut::Mut;
+use crate::sparse_set::FullRawWindowMut;
+use alloc::vec::Vec;
+
+/// Iterator over all unordered combinations of `K` distinct entities matching a query.
+///
+/// Created with [`IntoIter::iter_combinations`] or [`ViewMut::iter_combinations_mut`].\
+/// Each combination is yielded once, in the order of the query's iteration.
+///
+/// Combinations of mutable components can't be collected, they're yielded one at a time by [`fetch_next`](Combinations::fetch_next).
+///
+/// [`IntoIter::iter_combinations`]: crate::IntoIter::iter_combinations
+/// [`ViewMut::iter_combinations_mut`]: crate::ViewMut::iter_combinations_mut
+pub struct Combinations<Storage: AbstractMut, const K: usize> {
+    storage: Storage,
+    entities: Vec<EntityId>,
+    indices: Vec<Storage::Index>,
+    cursor: [usize; K],
+    last: [usize; K],
+    is_done: bool,
+}
+
+impl<Storage: AbstractMut, const K: usize> Combinations<Storage, K> {
+    /// Returns the entities of the last combination yielded.
+    ///
+    /// ### Panics
+    ///
+    /// - No combination was yielded yet.
+    #[track_caller]
+    pub fn ids(&self) -> [EntityId; K] {
+        assert!(
+            K == 0 || self.last[0] != usize::MAX,
+            "no combination was yielded yet"
+        );
+
+        core::array::from_fn(|i| self.entities[self.last[i]])
+    }
+    /// Returns the indices of the next combination and moves the cursor.
+    fn next_indices(&mut self) -> Option<[usize; K]> {
+        if self.is_done {
+            return None;
+        }
+
+        let current = self.cursor;
+        let len = self.entities.len();
+
+        self.is_done = true;
+        for i in (0..K).rev() {
+            if self.cursor[i] < len - K + i {
+                self.cursor[i] += 1;
+                for j in i + 1..K {
+                    self.cursor[j] = self.cursor[j - 1] + 1;
+                }
+
+                self.is_done = false;
+                break;
+            }
+        }
+
+        self.last = current;
+
+        Some(current)
+    }
+}
+
+impl<'w, T: Component, const K: usize> Combinations<FullRawWindowMut<'w, T>, K> {
+    /// Returns the next combination of mutable components.\
+    /// The components can't outlive this call, the next combination can share some of them.
+    pub fn fetch_next(&mut self) -> Option<[Mut<'_, T>; K]> {
+        let indices = self.next_indices()?;
+
+        // SAFE the indices of a combination are distinct
+        Some(core::array::from_fn(|i| unsafe {
+            self.storage.get_datas(self.indices[indices[i]])
+        }))
+    }
+}
+
+impl<Storage: AbstractMut, const K: usize> Iterator for Combinations<Storage, K>
+where
+    Storage::Out: Copy,
+{
+    type Item = [Storage::Out; K];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.next_indices()?;
+
+        // SAFE `Out` is `Copy` so it can't hold a mutable reference
+        Some(core::array::from_fn(|i| unsafe {
+            self.storage.get_datas(self.indices[indices[i]].clone())
+        }))
+    }
+}
+
+/// Creates a [`Combinations`] from an iterator.
+#[doc(hidden)]
+pub trait IntoCombinations {
+    type Storage: AbstractMut;
+
+    fn into_combinations<const K: usize>(self) -> Combinations<Self::Storage, K>;
+}
+
+impl<Storage: AbstractMut> IntoCombinations for Iter<Storage> {
+    type Storage = Storage;
+
+    fn into_combinations<const K: usize>(self) -> Combinations<Storage, K> {
+        let mut entities = Vec::new();
+        let mut indices = Vec::new();
+
+        // Looks up the indices the same way the iterator would, without accessing the components
+        let storage = match self {
+            Iter::Tight(tight) => {
+                for position in tight.current..tight.end {
+                    let entity = unsafe { tight.storage.get_id(position) };
+
+                    if let Some(index) = tight.storage.indices_of(entity, position, 0) {
+                        entities.push(entity);
+                        indices.push(index);
+                    }
+                }
+
+                tight.storage
+            }
+            Iter::Mixed(mut mixed) => loop {
+                for &entity in mixed.indices.by_ref() {
+                    mixed.count += 1;
+
+                    if let Some(index) =
+                        mixed
+                            .storage
+                            .indices_of(entity, mixed.count - 1, mixed.mask)
+                    {
+                        entities.push(entity);
+                        indices.push(index);
+                    }
+                }
+
+                match mixed.rev_next_storage.pop() {
+                    Some(next_indices) => mixed.indices = next_indices,
+                    None => break mixed.storage,
+                }
+            },
+        };
+
+        Combinations {
+            storage,
+            is_done: K > entities.len(),
+            entities,
+            indices,
+            cursor: core::array::from_fn(|i| i),
+            last: [usize::MAX; K],
+        }
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use rayon::iter::plumbing::{
+    Consumer, Folder, Producer, Reducer, UnindexedConsumer, UnindexedProducer,
+};
+
+/// Maximum number of entities processed by a single chunk of a parallel iterator in deterministic mode.
+///
+/// The iterator is split in half until each part is at most this long, the results are then reduced in the same order every run.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub const DETERMINISTIC_CHUNK_LEN: usize = 1024;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DETERMINISTIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns `true` if parallel iterators created on this thread have to split deterministically.
+#[cfg(feature = "std")]
+pub(crate) fn is_deterministic() -> bool {
+    DETERMINISTIC.with(Cell::get)
+}
+
+/// The mode is stored in a thread local, without `std` parallel iterators always use rayon's splitting.
+#[cfg(not(feature = "std"))]
+pub(crate) fn is_deterministic() -> bool {
+    false
+}
+
+/// Runs `f` with parallel iterators splitting deterministically on this thread.
+#[cfg(feature = "std")]
+pub(crate) fn deterministic<R>(f: impl FnOnce() -> R) -> R {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            DETERMINISTIC.with(|deterministic| deterministic.set(self.0));
+        }
+    }
+
+    let _reset = Reset(DETERMINISTIC.with(|deterministic| deterministic.replace(true)));
+
+    f()
+}
+
+/// Runs `f`, parallel iterators can't be made deterministic without `std`.
+#[cfg(not(feature = "std"))]
+pub(crate) fn deterministic<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Producer that rayon's adaptive splitter can't split.
+///
+/// Used when an adaptor takes control of the splitting, the iterator then runs on a single thread.
+pub(crate) struct Unsplittable<P>(pub(crate) P);
+
+impl<P: Producer> Producer for Unsplittable<P> {
+    type Item = P::Item;
+    type IntoIter = P::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+    fn min_len(&self) -> usize {
+        usize::MAX
+    }
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at(index);
+
+        (Unsplittable(left), Unsplittable(right))
+    }
+}
+
+/// Splits `producer` in half until chunks are at most [`DETERMINISTIC_CHUNK_LEN`] long.
+pub(crate) fn bridge_producer<P, C>(len: usize, producer: P, consumer: C) -> C::Result
+where
+    P: Producer,
+    C: Consumer<P::Item>,
+{
+    if len > DETERMINISTIC_CHUNK_LEN && !consumer.full() {
+        let mid = len / 2;
+        let (left_producer, right_producer) = producer.split_at(mid);
+        let (left_consumer, right_consumer, reducer) = consumer.split_at(mid);
+
+        let (left, right) = rayon::join(
+            || bridge_producer(mid, left_producer, left_consumer),
+            || bridge_producer(len - mid, right_producer, right_consumer),
+        );
+
+        reducer.reduce(left, right)
+    } else {
+        producer.fold_with(consumer.into_folder()).complete()
+    }
+}
+
+/// Splits `producer` until chunks are at most [`DETERMINISTIC_CHUNK_LEN`] long.\
+/// The length of a chunk is the upper bound of its size hint.
+pub(crate) fn bridge_unindexed<P, C>(producer: P, consumer: C) -> C::Result
+where
+    P: UnindexedProducer + Iterator,
+    C: UnindexedConsumer<<P as UnindexedProducer>::Item>,
+{
+    let len = producer.size_hint().1.unwrap_or(usize::MAX);
+
+    if len <= DETERMINISTIC_CHUNK_LEN || consumer.full() {
+        return producer.fold_with(consumer.into_folder()).complete();
+    }
+
+    match producer.split() {
+        (left_producer, Some(right_producer)) => {
+            let reducer = consumer.to_reducer();
+            let left_consumer = consumer.split_off_left();
+
+            let (left, right) = rayon::join(
+                || bridge_unindexed(left_producer, left_consumer),
+                || bridge_unindexed(right_producer, consumer),
+            );
+
+            reducer.reduce(left, right)
+        }
+        (producer, None) => producer.fold_with(consumer.into_folder()).complete(),
+    }
+}
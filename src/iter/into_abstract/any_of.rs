@@ -0,0 +1,70 @@
+use super::IntoAbstract;
+use crate::any_of::AnyOf;
+use crate::entity_id::EntityId;
+use crate::iter::abstract_mut::AbstractMut;
+use crate::type_id::TypeId;
+use alloc::vec::Vec;
+
+macro_rules! impl_into_abstract_any_of {
+    (($type1: ident, $index1: tt) $(($type: ident, $index: tt))+) => {
+        impl<$type1: IntoAbstract, $($type: IntoAbstract),+> IntoAbstract for AnyOf<($type1, $($type,)+)>
+        where
+            AnyOf<($type1::AbsView, $($type::AbsView,)+)>: AbstractMut,
+        {
+            type AbsView = AnyOf<($type1::AbsView, $($type::AbsView,)+)>;
+
+            fn into_abstract(self) -> Self::AbsView {
+                AnyOf(((self.0).$index1.into_abstract(), $((self.0).$index.into_abstract(),)+))
+            }
+            fn len(&self) -> Option<usize> {
+                (self.0).$index1.len()
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<AnyOf<()>>()
+            }
+            #[inline]
+            fn inner_type_id(&self) -> TypeId {
+                TypeId::of::<()>()
+            }
+            fn dense(&self) -> *const EntityId {
+                (self.0).$index1.dense()
+            }
+            fn other_dense(&self) -> Vec<core::slice::Iter<'static, EntityId>> {
+                let mut other_dense = Vec::new();
+
+                $(
+                    let slice = unsafe {
+                        core::slice::from_raw_parts(
+                            (self.0).$index.dense(),
+                            (self.0).$index.len().unwrap_or(0),
+                        )
+                    };
+                    other_dense.push(slice.iter());
+                )+
+
+                // Mixed pops the next storage from the end
+                other_dense.reverse();
+
+                other_dense
+            }
+            fn is_or(&self) -> bool {
+                true
+            }
+            fn version(&self) -> Option<u64> {
+                Some((self.0).$index1.version()?$(.wrapping_add((self.0).$index.version()?))+)
+            }
+        }
+    };
+}
+
+macro_rules! into_abstract_any_of {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_abstract_any_of![$(($type, $index))+];
+        into_abstract_any_of![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_into_abstract_any_of![$(($type, $index))*];
+    }
+}
+
+into_abstract_any_of![(A, 0) (B, 1); (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,75 @@
+use super::IntoAbstract;
+use crate::entity_id::EntityId;
+use crate::iter::abstract_mut::AbstractMut;
+use crate::tracking::Changed;
+use crate::type_id::TypeId;
+
+macro_rules! impl_into_abstract_changed {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: IntoAbstract),+> IntoAbstract for Changed<($($type,)+)>
+        where
+            Changed<($($type::AbsView,)+)>: AbstractMut,
+        {
+            type AbsView = Changed<($($type::AbsView,)+)>;
+
+            fn into_abstract(self) -> Self::AbsView {
+                Changed {
+                    views: ($(self.views.$index.into_abstract(),)+),
+                    since: self.since,
+                }
+            }
+            fn len(&self) -> Option<usize> {
+                let mut smallest: Option<usize> = None;
+
+                $(
+                    if !self.views.$index.is_not() && !self.views.$index.is_or() {
+                        if let Some(len) = self.views.$index.len() {
+                            if smallest.map_or(true, |smallest| len < smallest) {
+                                smallest = Some(len);
+                            }
+                        }
+                    }
+                )+
+
+                smallest
+            }
+            fn is_tracking(&self) -> bool {
+                true
+            }
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<Changed<()>>()
+            }
+            #[inline]
+            fn inner_type_id(&self) -> TypeId {
+                TypeId::of::<()>()
+            }
+            fn dense(&self) -> *const EntityId {
+                let mut smallest: Option<(usize, *const EntityId)> = None;
+
+                $(
+                    if !self.views.$index.is_not() && !self.views.$index.is_or() {
+                        if let Some(len) = self.views.$index.len() {
+                            if smallest.map_or(true, |(smallest, _)| len < smallest) {
+                                smallest = Some((len, self.views.$index.dense()));
+                            }
+                        }
+                    }
+                )+
+
+                smallest.map_or(core::ptr::null(), |(_, dense)| dense)
+            }
+        }
+    };
+}
+
+macro_rules! into_abstract_changed {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_abstract_changed![$(($type, $index))+];
+        into_abstract_changed![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_into_abstract_changed![$(($type, $index))*];
+    }
+}
+
+into_abstract_changed![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -1,8 +1,13 @@
+mod any_of;
+mod changed;
+mod deleted;
 mod inserted;
 mod inserted_or_modified;
 mod modified;
 mod not;
+mod optional;
 mod or;
+mod removed;
 
 use super::abstract_mut::AbstractMut;
 use crate::component::Component;
@@ -48,9 +53,19 @@ pub trait IntoAbstract {
         false
     }
     #[doc(hidden)]
+    fn is_optional(&self) -> bool {
+        false
+    }
+    #[doc(hidden)]
     fn other_dense(&self) -> Vec<core::slice::Iter<'static, EntityId>> {
         Vec::new()
     }
+    /// Changes each time an entity is added to or removed from the storages.\
+    /// `None` if the entities matching can change without it.
+    #[doc(hidden)]
+    fn version(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl<'a, T: Component, TRACK> IntoAbstract for &'a View<'a, T, TRACK> {
@@ -76,6 +91,10 @@ impl<'a, T: Component, TRACK> IntoAbstract for &'a View<'a, T, TRACK> {
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn version(&self) -> Option<u64> {
+        Some(self.sparse_set.version)
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b ViewMut<'a, T, TRACK> {
@@ -101,6 +120,10 @@ impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b ViewMut<'a, T, TRACK>
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn version(&self) -> Option<u64> {
+        Some(self.sparse_set.version)
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b mut ViewMut<'a, T, TRACK> {
@@ -126,4 +149,8 @@ impl<'a: 'b, 'b, T: Component, TRACK> IntoAbstract for &'b mut ViewMut<'a, T, TR
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[inline]
+    fn version(&self) -> Option<u64> {
+        Some(self.sparse_set.version)
+    }
 }
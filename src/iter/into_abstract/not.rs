@@ -32,4 +32,7 @@ where
     fn is_not(&self) -> bool {
         true
     }
+    fn version(&self) -> Option<u64> {
+        self.0.version()
+    }
 }
@@ -0,0 +1,34 @@
+use super::IntoAbstract;
+use crate::entity_id::EntityId;
+use crate::iter::abstract_mut::AbstractMut;
+use crate::optional::Optional;
+use crate::type_id::TypeId;
+
+impl<T: IntoAbstract> IntoAbstract for Optional<T>
+where
+    Optional<T::AbsView>: AbstractMut,
+{
+    type AbsView = Optional<T::AbsView>;
+
+    fn into_abstract(self) -> Self::AbsView {
+        Optional(self.0.into_abstract())
+    }
+    fn len(&self) -> Option<usize> {
+        None
+    }
+    fn type_id(&self) -> TypeId {
+        self.0.type_id()
+    }
+    fn inner_type_id(&self) -> TypeId {
+        self.0.inner_type_id()
+    }
+    fn dense(&self) -> *const EntityId {
+        self.0.dense()
+    }
+    fn is_optional(&self) -> bool {
+        true
+    }
+    fn version(&self) -> Option<u64> {
+        self.0.version()
+    }
+}
@@ -37,4 +37,7 @@ where
     fn is_or(&self) -> bool {
         true
     }
+    fn version(&self) -> Option<u64> {
+        Some((self.0).0.version()?.wrapping_add((self.0).1.version()?))
+    }
 }
@@ -0,0 +1,31 @@
+use super::IntoAbstract;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::tracking::Removed;
+use crate::type_id::TypeId;
+
+impl<T: Component> IntoAbstract for Removed<'_, T> {
+    type AbsView = Self;
+
+    #[inline]
+    fn into_abstract(self) -> Self::AbsView {
+        self
+    }
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        Some(self.entities.len())
+    }
+    #[inline]
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<SparseSet<T>>()
+    }
+    #[inline]
+    fn inner_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    #[inline]
+    fn dense(&self) -> *const EntityId {
+        self.entities.as_ptr()
+    }
+}
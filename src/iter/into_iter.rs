@@ -1,6 +1,8 @@
 use super::abstract_mut::AbstractMut;
+use super::combinations::{Combinations, IntoCombinations};
 use super::into_abstract::IntoAbstract;
 use super::iter::Iter;
+use super::iter_for::IterFor;
 use super::mixed::Mixed;
 #[cfg(feature = "parallel")]
 use super::par_iter::ParIter;
@@ -51,6 +53,10 @@ pub trait IntoIter {
     fn iter_by<D: 'static>(self) -> Self::IntoIter;
     /// Returns a parallel iterator over `SparseSet`.
     ///
+    /// The iterator runs in the [`ThreadPool`](rayon::ThreadPool) of the thread driving it.\
+    /// Systems of a workload run in the `World`'s local [`ThreadPool`](rayon::ThreadPool) when it has one.
+    /// With [`World::run`] or [`World::borrow`], the iterator has to be driven inside [`World::install`] to use it.
+    ///
     /// ### Example
     /// ```
     /// use rayon::prelude::ParallelIterator;
@@ -73,9 +79,113 @@ pub trait IntoIter {
     ///     x.0 += y.0 as usize;
     /// });
     /// ```
+    ///
+    /// [`World::run`]: crate::World::run
+    /// [`World::borrow`]: crate::World::borrow
+    /// [`World::install`]: crate::World::install
     #[cfg(feature = "parallel")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
     fn par_iter(self) -> Self::IntoParIter;
+    /// Returns an iterator over the entities in `ids` that have all the components.\
+    /// Entities missing a component, deleted or already visited are skipped.
+    ///
+    /// The components are fetched the same way [`iter`](IntoIter::iter) would for a single entity.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, IntoWithId, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let e0 = world.add_entity((Position(0.0), Speed(1.0)));
+    /// let e1 = world.add_entity((Position(0.0),));
+    /// let e2 = world.add_entity((Position(0.0), Speed(2.0)));
+    ///
+    /// let selection = vec![e2, e1, e0];
+    ///
+    /// let (mut positions, speeds) = world.borrow::<(ViewMut<Position>, View<Speed>)>().unwrap();
+    ///
+    /// let moved = (&mut positions, &speeds)
+    ///     .iter_for(selection.iter().copied())
+    ///     .with_id()
+    ///     .map(|(id, (mut position, speed))| {
+    ///         position.0 += speed.0;
+    ///         id
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(moved, [e2, e0]);
+    /// ```
+    fn iter_for<Storage, Ids>(self, ids: Ids) -> IterFor<Storage, Ids::IntoIter>
+    where
+        Self: IntoIter<IntoIter = Iter<Storage>> + Sized,
+        Ids: IntoIterator<Item = EntityId>,
+    {
+        let storage = match self.iter() {
+            Iter::Tight(tight) => tight.storage,
+            Iter::Mixed(mixed) => mixed.storage,
+        };
+
+        IterFor {
+            storage,
+            ids: ids.into_iter(),
+            visited: Default::default(),
+            last_id: EntityId::dead(),
+        }
+    }
+    /// Returns an iterator over all unordered combinations of `K` distinct entities matching the query.\
+    /// Only available for shared views, see [`ViewMut::iter_combinations_mut`] for mutable components.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Radius(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Position(0.0), Radius(1.0)));
+    /// world.add_entity((Position(1.5), Radius(1.0)));
+    /// world.add_entity((Position(5.0), Radius(1.0)));
+    ///
+    /// let (positions, radii) = world.borrow::<(View<Position>, View<Radius>)>().unwrap();
+    ///
+    /// let collisions = (&positions, &radii)
+    ///     .iter_combinations::<2>()
+    ///     .filter(|[(p0, r0), (p1, r1)]| (p0.0 - p1.0).abs() < r0.0 + r1.0)
+    ///     .count();
+    ///
+    /// assert_eq!(collisions, 1);
+    /// ```
+    ///
+    /// [`ViewMut::iter_combinations_mut`]: crate::ViewMut::iter_combinations_mut
+    #[allow(clippy::type_complexity)]
+    fn iter_combinations<const K: usize>(
+        self,
+    ) -> Combinations<<Self::IntoIter as IntoCombinations>::Storage, K>
+    where
+        Self: Sized,
+        Self::IntoIter: IntoCombinations,
+        <<Self::IntoIter as IntoCombinations>::Storage as AbstractMut>::Out: Copy,
+    {
+        self.iter().into_combinations()
+    }
+    /// Changes each time an entity is added to or removed from the storages.\
+    /// `None` if the entities matching can change without it.
+    #[doc(hidden)]
+    fn version(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl<T: IntoAbstract> IntoIter for T
@@ -123,6 +233,10 @@ where
     fn par_iter(self) -> Self::IntoParIter {
         self.iter().into()
     }
+    #[inline]
+    fn version(&self) -> Option<u64> {
+        IntoAbstract::version(self)
+    }
 }
 
 impl<T: IntoAbstract> IntoIter for (T,)
@@ -170,6 +284,10 @@ where
     fn par_iter(self) -> Self::IntoParIter {
         self.iter().into()
     }
+    #[inline]
+    fn version(&self) -> Option<u64> {
+        self.0.version()
+    }
 }
 
 macro_rules! impl_into_iter {
@@ -258,7 +376,7 @@ macro_rules! impl_into_iter {
                 let mut smallest_dense = ptr::null();
                 let mut mask: u16 = 0;
 
-                if self.$index1.inner_type_id() == type_id {
+                if !self.$index1.is_optional() && self.$index1.inner_type_id() == type_id {
                     found = true;
 
                     match self.$index1.len() {
@@ -280,7 +398,7 @@ macro_rules! impl_into_iter {
                 }
 
                 $(
-                    if !found && self.$index.inner_type_id() == type_id {
+                    if !found && !self.$index.is_optional() && self.$index.inner_type_id() == type_id {
                         found = true;
 
                         match self.$index.len() {
@@ -333,6 +451,10 @@ macro_rules! impl_into_iter {
             fn par_iter(self) -> Self::IntoParIter {
                 self.iter().into()
             }
+            #[inline]
+            fn version(&self) -> Option<u64> {
+                Some(self.$index1.version()?$(.wrapping_add(self.$index.version()?))+)
+            }
         }
     }
 }
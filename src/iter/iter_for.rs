@@ -0,0 +1,54 @@
+use super::abstract_mut::AbstractMut;
+use super::with_id::LastId;
+use crate::entity_id::EntityId;
+use hashbrown::HashSet;
+
+/// Iterator over the components of an explicit list of entities.\
+/// Created by [`IntoIter::iter_for`].
+///
+/// Entities missing a component or visited already are skipped.
+///
+/// [`IntoIter::iter_for`]: crate::IntoIter::iter_for
+pub struct IterFor<Storage, Ids> {
+    pub(crate) storage: Storage,
+    pub(crate) ids: Ids,
+    pub(crate) visited: HashSet<EntityId>,
+    pub(crate) last_id: EntityId,
+}
+
+impl<Storage: AbstractMut, Ids: Iterator<Item = EntityId>> Iterator for IterFor<Storage, Ids> {
+    type Item = Storage::Out;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for entity in self.ids.by_ref() {
+            if let Some(index) = self.storage.indices_of_passenger(entity, 0, 0) {
+                // yielding the same entity twice would alias mutable components
+                if !self.visited.insert(entity) {
+                    continue;
+                }
+
+                self.last_id = entity;
+
+                return Some(unsafe { self.storage.get_datas(index) });
+            }
+        }
+
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.ids.size_hint().1)
+    }
+}
+
+impl<Storage: AbstractMut, Ids: Iterator<Item = EntityId>> LastId for IterFor<Storage, Ids> {
+    #[inline]
+    unsafe fn last_id(&self) -> EntityId {
+        self.last_id
+    }
+    #[inline]
+    unsafe fn last_id_back(&self) -> EntityId {
+        self.last_id
+    }
+}
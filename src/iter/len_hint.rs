@@ -0,0 +1,108 @@
+use rayon::iter::plumbing::{
+    bridge_unindexed, Folder, Producer, Reducer, UnindexedConsumer, UnindexedProducer,
+};
+
+/// Producer splitting into parts between `min_len` and `max_len` long.
+pub(crate) struct LenHint<P> {
+    pub(crate) producer: P,
+    pub(crate) min_len: usize,
+    pub(crate) max_len: usize,
+}
+
+impl<P: Producer> Producer for LenHint<P> {
+    type Item = P::Item;
+    type IntoIter = P::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.producer.into_iter()
+    }
+    fn min_len(&self) -> usize {
+        self.min_len.max(self.producer.min_len())
+    }
+    fn max_len(&self) -> usize {
+        self.max_len.min(self.producer.max_len())
+    }
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.producer.split_at(index);
+
+        (
+            LenHint {
+                producer: left,
+                min_len: self.min_len,
+                max_len: self.max_len,
+            },
+            LenHint {
+                producer: right,
+                min_len: self.min_len,
+                max_len: self.max_len,
+            },
+        )
+    }
+}
+
+/// Unindexed producer that doesn't split into parts shorter than `min_len`.\
+/// The length of a part is the upper bound of its size hint.
+struct MinLen<P> {
+    producer: P,
+    min_len: usize,
+}
+
+impl<P: UnindexedProducer + Iterator> UnindexedProducer for MinLen<P> {
+    type Item = <P as UnindexedProducer>::Item;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let len = self.producer.size_hint().1.unwrap_or(usize::MAX);
+
+        if len / 2 < self.min_len {
+            return (self, None);
+        }
+
+        let min_len = self.min_len;
+        let (left, right) = self.producer.split();
+
+        (
+            MinLen {
+                producer: left,
+                min_len,
+            },
+            right.map(|producer| MinLen { producer, min_len }),
+        )
+    }
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        self.producer.fold_with(folder)
+    }
+}
+
+/// Splits `producer` until parts are at most `max_len` long, then lets rayon split them further down to `min_len`.\
+/// Parts are never split below `min_len`, even when they are longer than `max_len`.
+pub(crate) fn bridge_unindexed_with_len<P, C>(
+    producer: P,
+    min_len: usize,
+    max_len: usize,
+    consumer: C,
+) -> C::Result
+where
+    P: UnindexedProducer + Iterator,
+    C: UnindexedConsumer<<P as UnindexedProducer>::Item>,
+{
+    let len = producer.size_hint().1.unwrap_or(usize::MAX);
+
+    if len <= max_len || len / 2 < min_len || consumer.full() {
+        return bridge_unindexed(MinLen { producer, min_len }, consumer);
+    }
+
+    match producer.split() {
+        (left_producer, Some(right_producer)) => {
+            let reducer = consumer.to_reducer();
+            let left_consumer = consumer.split_off_left();
+
+            let (left, right) = rayon::join(
+                || bridge_unindexed_with_len(left_producer, min_len, max_len, left_consumer),
+                || bridge_unindexed_with_len(right_producer, min_len, max_len, consumer),
+            );
+
+            reducer.reduce(left, right)
+        }
+        (producer, None) => producer.fold_with(consumer.into_folder()).complete(),
+    }
+}
@@ -95,24 +95,33 @@ impl<Storage: AbstractMut + Clone + Send> UnindexedProducer for Mixed<Storage> {
     fn split(mut self) -> (Self, Option<Self>) {
         let len = self.indices.len();
 
-        if len >= 2 || !self.rev_next_storage.is_empty() {
+        if len >= 2 {
             let indices = self.indices.as_slice();
             let (first, second) = indices.split_at(indices.len() / 2);
-            let second_next = self
-                .rev_next_storage
-                .split_off(self.rev_next_storage.len() / 2);
 
+            // the second half continues with the next storages to keep `count` in order
             let clone = Mixed {
                 storage: self.storage.clone(),
                 indices: second.iter(),
                 count: self.count + (len / 2),
                 mask: self.mask,
                 last_id: self.last_id,
-                rev_next_storage: second_next,
+                rev_next_storage: core::mem::take(&mut self.rev_next_storage),
             };
 
             self.indices = first.iter();
 
+            (self, Some(clone))
+        } else if let Some(next_indices) = self.rev_next_storage.pop() {
+            let clone = Mixed {
+                storage: self.storage.clone(),
+                indices: next_indices,
+                count: self.count + len,
+                mask: self.mask,
+                last_id: self.last_id,
+                rev_next_storage: core::mem::take(&mut self.rev_next_storage),
+            };
+
             (self, Some(clone))
         } else {
             (self, None)
@@ -1,10 +1,17 @@
 //! Iterators types and traits.
 
 mod abstract_mut;
+mod chunks;
+mod combinations;
+#[cfg(feature = "parallel")]
+mod deterministic;
 mod into_abstract;
 mod into_iter;
 #[allow(clippy::module_inception)]
 mod iter;
+mod iter_for;
+#[cfg(feature = "parallel")]
+mod len_hint;
 mod mixed;
 #[cfg(feature = "parallel")]
 mod par_iter;
@@ -12,13 +19,23 @@ mod par_iter;
 mod par_mixed;
 #[cfg(feature = "parallel")]
 mod par_tight;
+mod query_state;
 mod tight;
 mod with_id;
 
 pub use abstract_mut::AbstractMut;
+pub use chunks::{AbstractChunk, Chunks, ChunksMut, GroupedChunks, IntoChunks};
+pub use combinations::Combinations;
+#[doc(hidden)]
+pub use combinations::IntoCombinations;
+#[cfg(feature = "parallel")]
+pub(crate) use deterministic::deterministic;
+#[cfg(feature = "parallel")]
+pub use deterministic::DETERMINISTIC_CHUNK_LEN;
 pub use into_abstract::IntoAbstract;
 pub use into_iter::IntoIter;
 pub use iter::Iter;
+pub use iter_for::IterFor;
 pub use mixed::Mixed;
 #[cfg(feature = "parallel")]
 pub use par_iter::ParIter;
@@ -26,5 +43,6 @@ pub use par_iter::ParIter;
 pub use par_mixed::ParMixed;
 #[cfg(feature = "parallel")]
 pub use par_tight::ParTight;
+pub use query_state::{QueryIter, QueryState};
 pub use tight::Tight;
 pub use with_id::{IntoWithId, LastId, WithId};
@@ -20,6 +20,83 @@ impl<Storage: AbstractMut> From<Iter<Storage>> for ParIter<Storage> {
     }
 }
 
+impl<Storage: AbstractMut + Clone + Send> ParIter<Storage> {
+    /// Prevents rayon from splitting the iterator in parts visiting less than `min_len` entities.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::ParallelIterator;
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..100).map(U32));
+    ///
+    /// let sum: u32 = world
+    ///     .borrow::<View<U32>>()
+    ///     .unwrap()
+    ///     .par_iter()
+    ///     .with_min_len(32)
+    ///     .map(|u| u.0)
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 4950);
+    /// ```
+    pub fn with_min_len(self, min_len: usize) -> Self {
+        match self {
+            ParIter::Tight(tight) => ParIter::Tight(tight.with_min_len(min_len)),
+            ParIter::Mixed(mixed) => ParIter::Mixed(mixed.with_min_len(min_len)),
+        }
+    }
+    /// Makes rayon split the iterator in parts visiting at most `max_len` entities.\
+    /// `min_len` takes precedence.
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        match self {
+            ParIter::Tight(tight) => ParIter::Tight(tight.with_max_len(max_len)),
+            ParIter::Mixed(mixed) => ParIter::Mixed(mixed.with_max_len(max_len)),
+        }
+    }
+    /// Calls `f` in parallel with sequential iterators visiting at most `chunk_size` entities.\
+    /// Entities missing a component are skipped so chunks can yield less than `chunk_size` items.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..100).map(U32));
+    ///
+    /// world
+    ///     .borrow::<ViewMut<U32>>()
+    ///     .unwrap()
+    ///     .par_iter()
+    ///     .par_for_each_chunk(16, |chunk| {
+    ///         assert!(chunk.count() <= 16);
+    ///     });
+    /// ```
+    #[track_caller]
+    pub fn par_for_each_chunk<F>(self, chunk_size: usize, f: F)
+    where
+        F: Fn(Iter<Storage>) + Sync + Send,
+    {
+        match self {
+            ParIter::Tight(tight) => tight.par_for_each_chunk(chunk_size, f),
+            ParIter::Mixed(mixed) => mixed.par_for_each_chunk(chunk_size, f),
+        }
+    }
+}
+
 impl<Storage: AbstractMut> ParallelIterator for ParIter<Storage>
 where
     Storage: Clone + Send,
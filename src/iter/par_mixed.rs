@@ -1,14 +1,107 @@
 use super::abstract_mut::AbstractMut;
+use super::deterministic;
+use super::iter::Iter;
+use super::len_hint::bridge_unindexed_with_len;
 use super::mixed::Mixed;
 use rayon::iter::plumbing::{bridge_unindexed, UnindexedConsumer};
 use rayon::iter::ParallelIterator;
 
 #[allow(missing_docs)]
-pub struct ParMixed<Storage>(Mixed<Storage>);
+pub struct ParMixed<Storage> {
+    mixed: Mixed<Storage>,
+    min_len: usize,
+    max_len: usize,
+}
 
 impl<Storage: AbstractMut> From<Mixed<Storage>> for ParMixed<Storage> {
-    fn from(iter: Mixed<Storage>) -> Self {
-        ParMixed(iter)
+    fn from(mixed: Mixed<Storage>) -> Self {
+        ParMixed {
+            mixed,
+            min_len: 1,
+            max_len: usize::MAX,
+        }
+    }
+}
+
+impl<Storage: AbstractMut + Clone + Send> ParMixed<Storage> {
+    /// Prevents rayon from splitting the iterator in parts visiting less than `min_len` entities.
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len.max(1);
+
+        self
+    }
+    /// Makes rayon split the iterator in parts visiting at most `max_len` entities.\
+    /// `min_len` takes precedence.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len.max(1);
+
+        self
+    }
+    /// Calls `f` in parallel with sequential iterators visiting at most `chunk_size` entities.\
+    /// Entities missing a component are skipped so chunks can yield less than `chunk_size` items.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    #[track_caller]
+    pub fn par_for_each_chunk<F>(self, chunk_size: usize, f: F)
+    where
+        F: Fn(Iter<Storage>) + Sync + Send,
+    {
+        assert!(chunk_size != 0, "chunk_size must be greater than 0");
+
+        for_each_chunk(self.mixed, chunk_size, &f);
+    }
+}
+
+/// Splits `mixed` at chunk boundaries until each part is a single chunk.\
+/// A part never spans two dense lists.
+fn for_each_chunk<Storage, F>(mut mixed: Mixed<Storage>, chunk_size: usize, f: &F)
+where
+    Storage: AbstractMut + Clone + Send,
+    F: Fn(Iter<Storage>) + Sync + Send,
+{
+    let len = mixed.indices.len();
+
+    let right = if len > chunk_size {
+        let chunk_count = len.div_ceil(chunk_size);
+        let mid = (chunk_count / 2) * chunk_size;
+        let indices = mixed.indices.as_slice();
+        let (first, second) = indices.split_at(mid);
+
+        let right = Mixed {
+            storage: mixed.storage.clone(),
+            indices: second.iter(),
+            count: mixed.count + mid,
+            mask: mixed.mask,
+            last_id: mixed.last_id,
+            rev_next_storage: core::mem::take(&mut mixed.rev_next_storage),
+        };
+
+        mixed.indices = first.iter();
+
+        Some(right)
+    } else if let Some(next_indices) = mixed.rev_next_storage.pop() {
+        Some(Mixed {
+            storage: mixed.storage.clone(),
+            indices: next_indices,
+            count: mixed.count + len,
+            mask: mixed.mask,
+            last_id: mixed.last_id,
+            rev_next_storage: core::mem::take(&mut mixed.rev_next_storage),
+        })
+    } else {
+        None
+    };
+
+    match right {
+        Some(right) => {
+            rayon::join(
+                || for_each_chunk(mixed, chunk_size, f),
+                || for_each_chunk(right, chunk_size, f),
+            );
+        }
+        None => f(Iter::Mixed(mixed)),
     }
 }
 
@@ -23,6 +116,12 @@ where
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        bridge_unindexed(self.0, consumer)
+        if deterministic::is_deterministic() {
+            deterministic::bridge_unindexed(self.mixed, consumer)
+        } else if self.min_len == 1 && self.max_len == usize::MAX {
+            bridge_unindexed(self.mixed, consumer)
+        } else {
+            bridge_unindexed_with_len(self.mixed, self.min_len, self.max_len, consumer)
+        }
     }
 }
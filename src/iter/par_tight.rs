@@ -1,14 +1,77 @@
 use super::abstract_mut::AbstractMut;
+use super::deterministic::{self, Unsplittable};
+use super::iter::Iter;
+use super::len_hint::LenHint;
 use super::tight::Tight;
-use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 
 #[allow(missing_docs)]
-pub struct ParTight<Storage>(Tight<Storage>);
+pub struct ParTight<Storage> {
+    tight: Tight<Storage>,
+    min_len: usize,
+    max_len: usize,
+}
 
 impl<Storage: AbstractMut> From<Tight<Storage>> for ParTight<Storage> {
-    fn from(iter: Tight<Storage>) -> Self {
-        ParTight(iter)
+    fn from(tight: Tight<Storage>) -> Self {
+        ParTight {
+            tight,
+            min_len: 1,
+            max_len: usize::MAX,
+        }
+    }
+}
+
+impl<Storage: AbstractMut + Clone + Send> ParTight<Storage> {
+    /// Prevents rayon from splitting the iterator in parts smaller than `min_len` components.
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len.max(1);
+
+        self
+    }
+    /// Makes rayon split the iterator in parts of at most `max_len` components.\
+    /// `min_len` takes precedence.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len.max(1);
+
+        self
+    }
+    /// Calls `f` in parallel with sequential iterators over `chunk_size` components.\
+    /// The last chunk can be shorter.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    #[track_caller]
+    pub fn par_for_each_chunk<F>(self, chunk_size: usize, f: F)
+    where
+        F: Fn(Iter<Storage>) + Sync + Send,
+    {
+        assert!(chunk_size != 0, "chunk_size must be greater than 0");
+
+        for_each_chunk(self.tight, chunk_size, &f);
+    }
+}
+
+/// Splits `tight` at chunk boundaries until each part is a single chunk.
+fn for_each_chunk<Storage, F>(tight: Tight<Storage>, chunk_size: usize, f: &F)
+where
+    Storage: AbstractMut + Clone + Send,
+    F: Fn(Iter<Storage>) + Sync + Send,
+{
+    let len = tight.end - tight.current;
+
+    if len > chunk_size {
+        let chunk_count = len.div_ceil(chunk_size);
+        let (left, right) = tight.split_at((chunk_count / 2) * chunk_size);
+
+        rayon::join(
+            || for_each_chunk(left, chunk_size, f),
+            || for_each_chunk(right, chunk_size, f),
+        );
+    } else {
+        f(Iter::Tight(tight));
     }
 }
 
@@ -23,7 +86,7 @@ where
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        bridge(self, consumer)
+        self.drive(consumer)
     }
     fn opt_len(&self) -> Option<usize> {
         Some(self.len())
@@ -36,12 +99,25 @@ where
     <Storage as AbstractMut>::Out: Send,
 {
     fn len(&self) -> usize {
-        self.0.len()
+        self.tight.len()
     }
     fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
-        bridge(self, consumer)
+        if deterministic::is_deterministic() {
+            deterministic::bridge_producer(self.len(), self.tight, consumer)
+        } else {
+            bridge(self, consumer)
+        }
     }
     fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
-        callback.callback(self.0)
+        // the adaptor decides how to split, the only deterministic option is to not split
+        if deterministic::is_deterministic() {
+            callback.callback(Unsplittable(self.tight))
+        } else {
+            callback.callback(LenHint {
+                producer: self.tight,
+                min_len: self.min_len,
+                max_len: self.max_len,
+            })
+        }
     }
 }
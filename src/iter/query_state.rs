@@ -0,0 +1,137 @@
+use super::abstract_mut::AbstractMut;
+use super::into_iter::IntoIter;
+use super::iter::Iter;
+use super::with_id::LastId;
+use crate::entity_id::EntityId;
+use alloc::vec::Vec;
+
+/// Remembers the entities matching a query between iterations.
+///
+/// The first iteration finds the matching entities like [`IntoIter::iter`] would and stores them.\
+/// The next iterations only visit the stored entities, as long as no entity was added to or removed from the queried storages.
+/// The cost of a sparse join is then proportional to the number of matches instead of the size of the smallest storage.
+///
+/// Queries using tracking filters like `inserted` or `modified` are never cached.\
+/// A `QueryState` should always be used with the same query and the same [`World`].
+///
+/// It is meant to be kept in a [`Local`] system parameter.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoIter, Local, QueryState, View, ViewMut, Workload, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// #[derive(Component)]
+/// struct Frozen;
+///
+/// fn thaw(mut query: Local<QueryState>, mut positions: ViewMut<Position>, frozen: View<Frozen>) {
+///     for (mut position, _) in query.iter((&mut positions, &frozen)) {
+///         position.0 += 1.0;
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// world.add_entity((Position(0.0), Frozen));
+/// world.add_entity((Position(0.0),));
+///
+/// Workload::new("Thaw").with_system(thaw).add_to_world(&world).unwrap();
+///
+/// world.run_workload("Thaw").unwrap();
+/// world.run_workload("Thaw").unwrap();
+/// ```
+///
+/// [`World`]: crate::World
+/// [`Local`]: crate::Local
+#[derive(Default, Clone, Debug)]
+pub struct QueryState {
+    entities: Vec<EntityId>,
+    version: Option<u64>,
+}
+
+impl QueryState {
+    /// Creates an empty `QueryState`.
+    pub fn new() -> QueryState {
+        QueryState::default()
+    }
+    /// Iterates the entities matching `query`.\
+    /// The matching entities are looked for again if entities were added to or removed from the queried storages.
+    pub fn iter<Q, Storage>(&mut self, query: Q) -> QueryIter<'_, Storage>
+    where
+        Q: IntoIter<IntoIter = Iter<Storage>>,
+        Storage: AbstractMut + Clone,
+    {
+        let version = query.version();
+        let mut iter = query.iter();
+        let storage = match &iter {
+            Iter::Tight(tight) => tight.storage.clone(),
+            Iter::Mixed(mixed) => mixed.storage.clone(),
+        };
+
+        if version.is_none() || version != self.version {
+            self.entities.clear();
+
+            while iter.next().is_some() {
+                self.entities.push(unsafe { iter.last_id() });
+            }
+
+            self.version = version;
+        }
+
+        QueryIter {
+            storage,
+            entities: self.entities.iter(),
+            last_id: EntityId::dead(),
+        }
+    }
+    /// Returns the entities found by the last iteration.
+    pub fn entities(&self) -> &[EntityId] {
+        &self.entities
+    }
+    /// Forgets the matching entities, they'll be looked for during the next iteration.
+    pub fn invalidate(&mut self) {
+        self.entities.clear();
+        self.version = None;
+    }
+}
+
+/// Iterator over the entities cached by a [`QueryState`].
+pub struct QueryIter<'a, Storage> {
+    storage: Storage,
+    entities: core::slice::Iter<'a, EntityId>,
+    last_id: EntityId,
+}
+
+impl<Storage: AbstractMut> Iterator for QueryIter<'_, Storage> {
+    type Item = Storage::Out;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for &entity in self.entities.by_ref() {
+            if let Some(index) = self.storage.indices_of_passenger(entity, 0, 0) {
+                self.last_id = entity;
+
+                return Some(unsafe { self.storage.get_datas(index) });
+            }
+        }
+
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.entities.len()))
+    }
+}
+
+impl<Storage: AbstractMut> LastId for QueryIter<'_, Storage> {
+    #[inline]
+    unsafe fn last_id(&self) -> EntityId {
+        self.last_id
+    }
+    #[inline]
+    unsafe fn last_id_back(&self) -> EntityId {
+        self.last_id
+    }
+}
@@ -36,6 +36,7 @@ mod add_component;
 mod add_distinct_component;
 mod add_entity;
 mod all_storages;
+mod any_of;
 mod atomic_refcell;
 /// Allows access to helper types needed to implement `Borrow`.
 pub mod borrow;
@@ -45,14 +46,19 @@ mod delete;
 mod entities;
 mod entity_id;
 pub mod error;
+mod event;
 mod get;
 mod get_component;
 pub mod iter;
 mod iter_component;
+mod listener;
 mod memory_usage;
 mod r#mut;
+mod name;
 mod not;
+mod optional;
 mod or;
+mod prefab;
 mod public_transport;
 mod remove;
 mod reserve;
@@ -81,7 +87,11 @@ pub use crate::borrow::NonSync;
 pub use add_component::AddComponent;
 pub use add_distinct_component::AddDistinctComponent;
 pub use add_entity::AddEntity;
-pub use all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetain};
+pub use all_storages::{
+    AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetain, UntypedComponents, UntypedIter,
+    UntypedQuery,
+};
+pub use any_of::AnyOf;
 pub use atomic_refcell::{ARef, ARefMut};
 #[doc(hidden)]
 pub use atomic_refcell::{ExclusiveBorrow, SharedBorrow};
@@ -92,19 +102,25 @@ pub use contains::Contains;
 pub use delete::Delete;
 pub use entities::Entities;
 pub use entity_id::EntityId;
+pub use event::Events;
 pub use get::Get;
 pub use get_component::{GetComponent, Ref, RefMut};
-pub use iter::{IntoIter, IntoWithId};
+pub use iter::{IntoChunks, IntoIter, IntoWithId, QueryState};
 pub use iter_component::{IntoIterRef, IterComponent, IterRef};
+pub use listener::ListenerId;
 pub use memory_usage::StorageMemoryUsage;
+pub use name::{EntityLabel, Name, NamedDebug};
 pub use not::Not;
+pub use optional::Optional;
 pub use or::{OneOfTwo, Or};
+pub use prefab::{MapEntities, Prefab, PrefabComponent, TuplePrefabComponent};
 pub use r#mut::Mut;
 pub use remove::Remove;
 pub use reserve::{BulkEntityIter, BulkReserve};
 pub use scheduler::{
-    info, AsLabel, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label,
-    ScheduledWorkload, SystemModificator, Workload, WorkloadModificator, WorkloadSystem,
+    info, AsLabel, FixedTimestepAlpha, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem,
+    Label, PanicPolicy, ScheduledWorkload, SystemModificator, SystemSet, Time, Workload,
+    WorkloadModificator, WorkloadSystem,
 };
 #[cfg(feature = "proc")]
 pub use shipyard_proc::{Borrow, BorrowInfo, Component, Unique, WorldBorrow};
@@ -116,13 +132,13 @@ pub use storage::{Storage, StorageId};
 #[doc(hidden)]
 pub use system::{AllSystem, Nothing, System};
 pub use tracking::{
-    DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
-    Modified, RemovalOrDeletionTracking, RemovalTracking, Track, Tracking, TrackingTimestamp,
-    TupleTrack,
+    Changed, Deleted, DeletionTracking, Inserted, InsertedOrModified, InsertionTracking,
+    ModificationTracking, Modified, RemovalOrDeletionTracking, RemovalTracking, Removed, Track,
+    Tracking, TrackingTimestamp, TupleTrack,
 };
 pub use unique::UniqueStorage;
 pub use views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
-    View, ViewMut,
+    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, EventReader, EventWriter,
+    Local, SystemLocals, SystemLocalsCursor, UniqueView, UniqueViewMut, View, ViewMut,
 };
 pub use world::World;
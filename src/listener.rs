@@ -0,0 +1,55 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Identifies a listener registered on [`Entities`] or a [`SparseSet`].
+///
+/// Used to unregister the listener.
+///
+/// [`Entities`]: crate::Entities
+/// [`SparseSet`]: crate::SparseSet
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ListenerId(u64);
+
+/// List of callbacks invoked in registration order.
+pub(crate) struct Listeners<F: ?Sized> {
+    list: Vec<(ListenerId, Box<F>)>,
+}
+
+impl<F: ?Sized> Listeners<F> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Listeners { list: Vec::new() }
+    }
+    /// Adds `listener` to the list.\
+    /// `next_id` is shared between all lists of the same owner so a single id can be used to unregister from any of them.
+    pub(crate) fn push(&mut self, next_id: &mut u64, listener: Box<F>) -> ListenerId {
+        let id = ListenerId(*next_id);
+        *next_id += 1;
+
+        self.list.push((id, listener));
+
+        id
+    }
+    /// Removes the listener with the given id, returns `true` if it was in this list.
+    pub(crate) fn remove(&mut self, id: ListenerId) -> bool {
+        if let Some(index) = self
+            .list
+            .iter()
+            .position(|(listener_id, _)| *listener_id == id)
+        {
+            self.list.remove(index);
+
+            true
+        } else {
+            false
+        }
+    }
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+    #[inline]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut F> {
+        self.list.iter_mut().map(|(_, listener)| &mut **listener)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+
+/// Human-readable name of an entity.
+///
+/// Names are regular components, they are only used when present.\
+/// Views print them in their `Debug` output and when indexing panics, unless the `Name` storage is exclusively borrowed.\
+/// [`MissingComponent::with_names`](crate::error::MissingComponent::with_names) and [`SparseSet::debug_with_names`] can be used to print errors and storages with them.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Name, World};
+///
+/// let mut world = World::new();
+///
+/// let player = world.add_entity((Name::new("player_ship"),));
+///
+/// assert_eq!(world.entity_by_name("player_ship"), Some(player));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Name(pub Cow<'static, str>);
+
+impl Component for Name {}
+
+impl Name {
+    /// Creates a new `Name`.
+    #[inline]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Name {
+        Name(name.into())
+    }
+    /// Returns the name as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&'static str> for Name {
+    #[inline]
+    fn from(name: &'static str) -> Self {
+        Name(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for Name {
+    #[inline]
+    fn from(name: String) -> Self {
+        Name(Cow::Owned(name))
+    }
+}
+
+impl SparseSet<Name> {
+    /// Returns the first entity with the given name.\
+    /// Names are not indexed, this is a linear search.
+    pub fn entity_by_name(&self, name: &str) -> Option<EntityId> {
+        self.dense
+            .iter()
+            .zip(&self.data)
+            .find(|(_, entity_name)| entity_name.0 == name)
+            .map(|(&entity, _)| entity)
+    }
+    /// Returns `entity`'s name, if it has one.
+    #[inline]
+    pub fn name_of(&self, entity: EntityId) -> Option<&str> {
+        self.private_get(entity).map(Name::as_str)
+    }
+    /// Returns a value printing `entity` with its name, when it has one.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Name, View, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((Name::new("player_ship"),));
+    ///
+    /// world.run(|names: View<Name>| {
+    ///     assert_eq!(
+    ///         format!("{:?}", names.label(entity)),
+    ///         format!("player_ship ({:?})", entity)
+    ///     );
+    /// });
+    /// ```
+    #[inline]
+    pub fn label(&self, entity: EntityId) -> EntityLabel<'_> {
+        EntityLabel {
+            entity,
+            name: self.name_of(entity),
+        }
+    }
+}
+
+impl<T: Component> SparseSet<T> {
+    /// Returns a value printing this storage like its `Debug` implementation but with entities' names.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Name, View, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Name::new("player_ship"), Health(10)));
+    ///
+    /// world.run(|names: View<Name>, healths: View<Health>| {
+    ///     println!("{:?}", healths.debug_with_names(&names));
+    /// });
+    /// ```
+    #[inline]
+    pub fn debug_with_names<'a>(&'a self, names: &'a SparseSet<Name>) -> NamedDebug<'a, T> {
+        NamedDebug {
+            sparse_set: self,
+            names,
+        }
+    }
+}
+
+/// `EntityId` with its optional name.
+///
+/// Prints as `name (EntityId)` or `EntityId` for unnamed entities.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EntityLabel<'a> {
+    /// Labelled entity.
+    pub entity: EntityId,
+    /// Name of the entity.
+    pub name: Option<&'a str>,
+}
+
+impl fmt::Debug for EntityLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => f.write_fmt(format_args!("{} ({:?})", name, self.entity)),
+            None => fmt::Debug::fmt(&self.entity, f),
+        }
+    }
+}
+
+impl fmt::Display for EntityLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Debug representation of a storage using entities' names.
+///
+/// Returned by [`SparseSet::debug_with_names`].
+pub struct NamedDebug<'a, T: Component> {
+    sparse_set: &'a SparseSet<T>,
+    names: &'a SparseSet<Name>,
+}
+
+impl<T: fmt::Debug + Component> fmt::Debug for NamedDebug<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.sparse_set
+                    .dense
+                    .iter()
+                    .zip(&self.sparse_set.data)
+                    .map(|(&entity, component)| (self.names.label(entity), component)),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,35 @@
+/// Used to iterate components that might not be present.
+///
+/// An optional storage never drives iteration, entities without the component yield `None`.\
+/// Created with [`View::optional`], [`ViewMut::optional`] or [`ViewMut::optional_mut`].
+///
+/// Iterating an optional storage on its own yields nothing.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoIter, View, World};
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct U32(u32);
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct USIZE(usize);
+///
+/// let mut world = World::new();
+///
+/// world.add_entity((USIZE(0), U32(1)));
+/// world.add_entity((USIZE(2),));
+///
+/// let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
+///
+/// let mut iter = (&usizes, u32s.optional()).iter();
+/// assert_eq!(iter.next(), Some((&USIZE(0), Some(&U32(1)))));
+/// assert_eq!(iter.next(), Some((&USIZE(2), None)));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// [`View::optional`]: crate::View::optional
+/// [`ViewMut::optional`]: crate::ViewMut::optional
+/// [`ViewMut::optional_mut`]: crate::ViewMut::optional_mut
+#[derive(Copy, Clone)]
+pub struct Optional<T>(pub(crate) T);
@@ -0,0 +1,366 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Updates the [`EntityId`]s stored inside a component.
+///
+/// Used by [`Prefab`] to make references between the prefab's entities point to the newly spawned ones.
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Component, EntityId, MapEntities};
+///
+/// #[derive(Component, Clone)]
+/// struct Parent(EntityId);
+///
+/// impl MapEntities for Parent {
+///     fn map_entities(&mut self, map: &mut dyn FnMut(EntityId) -> EntityId) {
+///         self.0 = map(self.0);
+///     }
+/// }
+/// ```
+pub trait MapEntities {
+    /// Replaces every [`EntityId`] stored in `self` by the one returned by `map`.
+    fn map_entities(&mut self, map: &mut dyn FnMut(EntityId) -> EntityId);
+}
+
+/// Template used to spawn pre-configured entities.
+///
+/// A prefab is made of a root entity and optionally other entities, each one with any number of cloneable components.\
+/// Components can refer to the prefab's entities using the `EntityId`s returned by [`Prefab::root`] and [`Prefab::add_entity`],
+/// these ids will be remapped to the spawned entities when the component implements [`MapEntities`].
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{Component, EntityId, Get, MapEntities, Prefab, View, World};
+///
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone)]
+/// struct Turret(EntityId);
+///
+/// impl MapEntities for Turret {
+///     fn map_entities(&mut self, map: &mut dyn FnMut(EntityId) -> EntityId) {
+///         self.0 = map(self.0);
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// let mut prefab = Prefab::new();
+/// let turret = prefab.add_entity();
+/// prefab.add_component(turret, (Health(5),));
+/// prefab.add_component(prefab.root(), (Health(10),));
+/// prefab.add_component_with_entities(prefab.root(), Turret(turret));
+///
+/// world.add_prefab("tank", prefab);
+///
+/// let tank = world.spawn_prefab("tank").unwrap();
+///
+/// world.run(|healths: View<Health>, turrets: View<Turret>| {
+///     let turret = turrets.get(tank).unwrap().0;
+///
+///     assert_eq!(healths.get(tank).unwrap().0, 10);
+///     assert_eq!(healths.get(turret).unwrap().0, 5);
+/// });
+/// ```
+pub struct Prefab {
+    entities: Vec<Vec<Box<dyn PrefabComponent>>>,
+}
+
+impl Default for Prefab {
+    fn default() -> Self {
+        Prefab::new()
+    }
+}
+
+impl Prefab {
+    /// Creates a new `Prefab` with a single entity without component.
+    pub fn new() -> Prefab {
+        Prefab {
+            entities: alloc::vec![Vec::new()],
+        }
+    }
+    /// Returns the `EntityId` of the prefab's root entity.
+    /// This is the entity returned by [`World::spawn_prefab`](crate::World::spawn_prefab()).
+    #[inline]
+    pub fn root(&self) -> EntityId {
+        Prefab::local_id(0)
+    }
+    /// Adds an entity to the prefab and returns its `EntityId`.
+    /// This `EntityId` is only meaningful inside this prefab.
+    pub fn add_entity(&mut self) -> EntityId {
+        self.entities.push(Vec::new());
+
+        Prefab::local_id(self.entities.len() - 1)
+    }
+    /// Returns the number of entities a single spawn of this prefab will create.
+    #[inline]
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+    /// Adds components to one of the prefab's entities.
+    /// `component` can be a single component or a tuple of components.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not part of this prefab.
+    #[track_caller]
+    pub fn add_component<C: TuplePrefabComponent>(&mut self, entity: EntityId, component: C) {
+        let index = self.index_of(entity);
+
+        component.add_to_prefab(&mut self.entities[index]);
+    }
+    /// Adds a component referring to other entities to one of the prefab's entities.
+    /// When spawned, the prefab's `EntityId`s will be replaced by the ones of the new entities.
+    /// All other `EntityId`s are left untouched.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is not part of this prefab.
+    #[track_caller]
+    pub fn add_component_with_entities<T>(&mut self, entity: EntityId, component: T)
+    where
+        T: MapEntities + Clone + Send + Sync + Component,
+    {
+        let index = self.index_of(entity);
+
+        self.entities[index].push(Box::new(Mapped(component)));
+    }
+
+    // Prefab entities use the maximum generation so they can't be mistaken for a living entity.
+    #[inline]
+    fn local_id(index: usize) -> EntityId {
+        EntityId::new_from_parts(index as u64, EntityId::max_gen())
+    }
+    #[track_caller]
+    fn index_of(&self, entity: EntityId) -> usize {
+        if entity.gen() == EntityId::max_gen() && entity.uindex() < self.entities.len() {
+            entity.uindex()
+        } else {
+            panic!("{:?} is not part of this prefab.", entity);
+        }
+    }
+
+    /// Adds the components of all the prefab's entities to `new_entities`.
+    /// `new_entities[i]` is the spawned version of the prefab's entity `i`.
+    pub(crate) fn spawn_into(
+        &self,
+        all_storages: &mut AllStorages,
+        new_entities: &[EntityId],
+        current: u32,
+    ) {
+        let mut map = |entity: EntityId| {
+            if entity.gen() == EntityId::max_gen() {
+                new_entities.get(entity.uindex()).copied().unwrap_or(entity)
+            } else {
+                entity
+            }
+        };
+
+        for (components, &entity) in self.entities.iter().zip(new_entities) {
+            for component in components {
+                component.add_to(all_storages, entity, &mut map, current);
+            }
+        }
+    }
+    /// Adds the components of `count` copies of the prefab to `new_entities`.
+    /// `new_entities[i * count + n]` is the spawned version of the prefab's entity `i` in the `n`th copy.
+    pub(crate) fn bulk_spawn_into(
+        &self,
+        all_storages: &mut AllStorages,
+        new_entities: &[EntityId],
+        count: usize,
+        current: u32,
+    ) {
+        let mut map = |copy: usize, entity: EntityId| {
+            if entity.gen() == EntityId::max_gen() {
+                new_entities
+                    .get(entity.uindex() * count + copy)
+                    .copied()
+                    .unwrap_or(entity)
+            } else {
+                entity
+            }
+        };
+
+        for (components, entities) in self.entities.iter().zip(new_entities.chunks_exact(count)) {
+            for component in components {
+                component.bulk_add_to(all_storages, entities, &mut map, current);
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for Prefab {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.entities.iter().map(|components| {
+                components
+                    .iter()
+                    .map(|component| component.name())
+                    .collect::<Vec<_>>()
+            }))
+            .finish()
+    }
+}
+
+/// Type-erased prefab component.
+pub trait PrefabComponent: Send + Sync {
+    /// Adds a copy of this component to `entity`.
+    fn add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        map: &mut dyn FnMut(EntityId) -> EntityId,
+        current: u32,
+    );
+    /// Adds a copy of this component to each entity in `entities`.\
+    /// `map` takes the index of the entity in `entities` as first argument.
+    fn bulk_add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entities: &[EntityId],
+        map: &mut dyn FnMut(usize, EntityId) -> EntityId,
+        current: u32,
+    );
+    /// Returns the component's name.
+    fn name(&self) -> &'static str;
+}
+
+struct Cloned<T>(T);
+
+impl<T: Clone + Send + Sync + Component> PrefabComponent for Cloned<T> {
+    #[inline]
+    fn add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        _: &mut dyn FnMut(EntityId) -> EntityId,
+        current: u32,
+    ) {
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+            .insert(entity, self.0.clone(), current);
+    }
+    fn bulk_add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entities: &[EntityId],
+        _: &mut dyn FnMut(usize, EntityId) -> EntityId,
+        current: u32,
+    ) {
+        let sparse_set = bulk_reserve::<T>(all_storages, entities);
+
+        for &entity in entities {
+            sparse_set.insert(entity, self.0.clone(), current);
+        }
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+}
+
+struct Mapped<T>(T);
+
+impl<T: MapEntities + Clone + Send + Sync + Component> PrefabComponent for Mapped<T> {
+    #[inline]
+    fn add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        map: &mut dyn FnMut(EntityId) -> EntityId,
+        current: u32,
+    ) {
+        let mut component = self.0.clone();
+        component.map_entities(map);
+
+        all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+            .insert(entity, component, current);
+    }
+    fn bulk_add_to(
+        &self,
+        all_storages: &mut AllStorages,
+        entities: &[EntityId],
+        map: &mut dyn FnMut(usize, EntityId) -> EntityId,
+        current: u32,
+    ) {
+        let sparse_set = bulk_reserve::<T>(all_storages, entities);
+
+        for (copy, &entity) in entities.iter().enumerate() {
+            let mut component = self.0.clone();
+            component.map_entities(&mut |entity| map(copy, entity));
+
+            sparse_set.insert(entity, component, current);
+        }
+    }
+    fn name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+}
+
+/// Returns `T`'s storage after making room for a component for each entity in `entities`.
+fn bulk_reserve<'a, T: Send + Sync + Component>(
+    all_storages: &'a mut AllStorages,
+    entities: &[EntityId],
+) -> &'a mut SparseSet<T> {
+    let sparse_set = all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+    if let (Some(&first), Some(&last)) = (entities.first(), entities.last()) {
+        sparse_set.sparse.bulk_allocate(first, last);
+        sparse_set.reserve(entities.len());
+    }
+
+    sparse_set
+}
+
+/// Trait used as bound for [`Prefab::add_component`].
+pub trait TuplePrefabComponent {
+    /// See [`Prefab::add_component`].
+    fn add_to_prefab(self, components: &mut Vec<Box<dyn PrefabComponent>>);
+}
+
+impl TuplePrefabComponent for () {
+    #[inline]
+    fn add_to_prefab(self, _: &mut Vec<Box<dyn PrefabComponent>>) {}
+}
+
+impl<T: Clone + Send + Sync + Component> TuplePrefabComponent for T {
+    #[inline]
+    fn add_to_prefab(self, components: &mut Vec<Box<dyn PrefabComponent>>) {
+        components.push(Box::new(Cloned(self)));
+    }
+}
+
+macro_rules! impl_prefab_component {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: TuplePrefabComponent,)+> TuplePrefabComponent for ($($type,)+) {
+            fn add_to_prefab(self, components: &mut Vec<Box<dyn PrefabComponent>>) {
+                $(
+                    self.$index.add_to_prefab(components);
+                )+
+            }
+        }
+    };
+}
+
+macro_rules! prefab_component {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_prefab_component![$(($type, $index))*];
+        prefab_component![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_prefab_component![$(($type, $index))*];
+    }
+}
+
+prefab_component![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,121 @@
+use crate::component::Unique;
+use crate::error;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use core::time::Duration;
+
+/// Default maximum number of steps a fixed timestep workload runs per call.
+pub(crate) const DEFAULT_MAX_STEPS: u32 = 5;
+
+/// Frame time read by fixed timestep workloads.
+///
+/// Call [`advance`](Time::advance) once per frame before running the workloads.
+///
+/// ### Example
+/// ```
+/// use core::time::Duration;
+/// use shipyard::{Time, UniqueViewMut, World};
+///
+/// let world = World::new();
+///
+/// world.add_unique(Time::new());
+///
+/// world.run(|mut time: UniqueViewMut<Time>| {
+///     time.advance(Duration::from_millis(16));
+/// });
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Time {
+    delta: Duration,
+    elapsed: Duration,
+}
+
+impl Unique for Time {}
+
+impl Time {
+    /// Creates a new `Time` with no elapsed time.
+    #[inline]
+    pub fn new() -> Time {
+        Time::default()
+    }
+    /// Advances the clock by `delta`.
+    #[inline]
+    pub fn advance(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+    }
+    /// Duration of the last frame.
+    #[inline]
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+    /// Total duration since the clock was created.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Time accumulated by the last fixed timestep workload that did not make a full step, as a fraction of its timestep.\
+/// Always in the `0.0..1.0` range, it can be used to interpolate rendering between two steps.
+///
+/// Added to the `World` by the first run of a fixed timestep workload.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct FixedTimestepAlpha(pub f32);
+
+impl Unique for FixedTimestepAlpha {}
+
+/// Timestep of a workload and the time it has yet to simulate.
+pub(crate) struct FixedTimestep {
+    pub(crate) step: Duration,
+    pub(crate) max_steps: u32,
+    /// Set while the workload is advancing its timestep.
+    is_advancing: AtomicBool,
+    /// `Time::elapsed` of the previous run in nanoseconds, `u64::MAX` before the first run.
+    last_elapsed: AtomicU64,
+    /// Time yet to simulate in nanoseconds.
+    accumulator: AtomicU64,
+}
+
+impl FixedTimestep {
+    pub(crate) fn new(step: Duration, max_steps: u32) -> FixedTimestep {
+        FixedTimestep {
+            step,
+            max_steps,
+            is_advancing: AtomicBool::new(false),
+            last_elapsed: AtomicU64::new(u64::MAX),
+            accumulator: AtomicU64::new(0),
+        }
+    }
+    /// Accumulates the time elapsed since the last call and returns the number of steps to run and the leftover alpha.\
+    /// Steps above `max_steps` are dropped.
+    pub(crate) fn advance(&self, time: &Time) -> Result<(u32, f32), error::Borrow> {
+        if self
+            .is_advancing
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return Err(error::Borrow::Unique);
+        }
+
+        let elapsed = time.elapsed.as_nanos() as u64;
+        let delta = match self.last_elapsed.load(Ordering::Relaxed) {
+            u64::MAX => time.delta.as_nanos() as u64,
+            last_elapsed => elapsed.saturating_sub(last_elapsed),
+        };
+        self.last_elapsed.store(elapsed, Ordering::Relaxed);
+
+        let step = self.step.as_nanos() as u64;
+        let accumulator = self.accumulator.load(Ordering::Relaxed) + delta;
+
+        let steps = (accumulator / step).min(self.max_steps as u64) as u32;
+
+        let leftover = accumulator % step;
+        self.accumulator.store(leftover, Ordering::Relaxed);
+
+        self.is_advancing.store(false, Ordering::Release);
+
+        let alpha = (Duration::from_nanos(leftover).as_secs_f64() / self.step.as_secs_f64()) as f32;
+
+        Ok((steps, alpha))
+    }
+}
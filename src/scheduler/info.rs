@@ -6,8 +6,11 @@ use crate::storage::StorageId;
 pub use crate::type_id::TypeId;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
+use core::time::Duration;
 
 /// Contains information related to a workload.
 ///
@@ -18,6 +21,273 @@ pub struct WorkloadInfo {
     pub name: Box<dyn Label>,
     #[allow(missing_docs)]
     pub batch_info: Vec<BatchInfo>,
+    /// `before_all`/`after_all` requirements between systems of the workload.\
+    /// The first system runs before the second.
+    pub orderings: Vec<(SystemId, SystemId)>,
+}
+
+impl WorkloadInfo {
+    /// Renders the workload as a [Graphviz](https://graphviz.org) DOT graph.
+    ///
+    /// Systems are nodes grouped in a cluster per batch.\
+    /// Solid edges are `before_all`/`after_all` requirements, dashed edges are conflicts labelled with the type that caused them.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoWorkload, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// fn read(_: View<U32>) {}
+    /// fn write(_: ViewMut<U32>) {}
+    ///
+    /// let (_, info) = (read, write).into_workload().build().unwrap();
+    ///
+    /// assert!(info.to_dot().starts_with("digraph"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let nodes = self.nodes();
+        let mut dot = String::new();
+
+        writeln!(
+            dot,
+            "digraph \"{}\" {{",
+            escape_dot(&label_text(&*self.name))
+        )
+        .unwrap();
+        writeln!(dot, "    compound=true;").unwrap();
+
+        for (batch_index, batch_nodes) in self.batch_nodes(&nodes).enumerate() {
+            writeln!(dot, "    subgraph cluster_{} {{", batch_index).unwrap();
+            writeln!(dot, "        label=\"Batch {}\";", batch_index).unwrap();
+
+            for node in batch_nodes {
+                writeln!(
+                    dot,
+                    "        s{} [label=\"{}\"];",
+                    node.id,
+                    escape_dot(&node.label())
+                )
+                .unwrap();
+            }
+
+            writeln!(dot, "    }}").unwrap();
+        }
+
+        for (from, to, label) in self.edges(&nodes) {
+            match label {
+                Some(label) => writeln!(
+                    dot,
+                    "    s{} -> s{} [style=dashed, label=\"{}\"];",
+                    from,
+                    to,
+                    escape_dot(&label)
+                ),
+                None => writeln!(dot, "    s{} -> s{};", from, to),
+            }
+            .unwrap();
+        }
+
+        dot.push('}');
+        dot.push('\n');
+
+        dot
+    }
+    /// Renders the workload as a [Mermaid](https://mermaid.js.org) flowchart.
+    ///
+    /// Systems are nodes grouped in a subgraph per batch.\
+    /// Solid edges are `before_all`/`after_all` requirements, dotted edges are conflicts labelled with the type that caused them.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoWorkload, View, ViewMut, Workload};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// fn read(_: View<U32>) {}
+    /// fn write(_: ViewMut<U32>) {}
+    ///
+    /// let (_, info) = (read, write).into_workload().build().unwrap();
+    ///
+    /// assert!(info.to_mermaid().starts_with("flowchart"));
+    /// ```
+    pub fn to_mermaid(&self) -> String {
+        let nodes = self.nodes();
+        let mut mermaid = String::new();
+
+        writeln!(mermaid, "flowchart TD").unwrap();
+
+        for (batch_index, batch_nodes) in self.batch_nodes(&nodes).enumerate() {
+            writeln!(
+                mermaid,
+                "    subgraph batch_{}[\"Batch {}\"]",
+                batch_index, batch_index
+            )
+            .unwrap();
+
+            for node in batch_nodes {
+                writeln!(
+                    mermaid,
+                    "        s{}[\"{}\"]",
+                    node.id,
+                    escape_mermaid(&node.label())
+                )
+                .unwrap();
+            }
+
+            writeln!(mermaid, "    end").unwrap();
+        }
+
+        for (from, to, label) in self.edges(&nodes) {
+            match label {
+                Some(label) => writeln!(
+                    mermaid,
+                    "    s{} -.->|\"{}\"| s{}",
+                    from,
+                    escape_mermaid(&label),
+                    to
+                ),
+                None => writeln!(mermaid, "    s{} --> s{}", from, to),
+            }
+            .unwrap();
+        }
+
+        mermaid
+    }
+    /// Lists all systems in batch order.
+    fn nodes(&self) -> Vec<Node<'_>> {
+        let mut nodes = Vec::new();
+
+        for (batch, batch_info) in self.batch_info.iter().enumerate() {
+            for system in batch_info.systems.0.iter().chain(&batch_info.systems.1) {
+                nodes.push(Node {
+                    id: nodes.len(),
+                    batch,
+                    system,
+                });
+            }
+        }
+
+        nodes
+    }
+    fn batch_nodes<'a>(
+        &'a self,
+        nodes: &'a [Node<'a>],
+    ) -> impl Iterator<Item = impl Iterator<Item = &'a Node<'a>>> + 'a {
+        (0..self.batch_info.len())
+            .map(move |batch| nodes.iter().filter(move |node| node.batch == batch))
+    }
+    /// Lists requirement edges, without label, then conflict edges.
+    fn edges(&self, nodes: &[Node<'_>]) -> Vec<(usize, usize, Option<String>)> {
+        let mut edges = Vec::new();
+
+        for (before, after) in &self.orderings {
+            for from in nodes
+                .iter()
+                .filter(|node| node.system.type_id == before.type_id)
+            {
+                for to in nodes
+                    .iter()
+                    .filter(|node| node.system.type_id == after.type_id)
+                {
+                    edges.push((from.id, to.id, None));
+                }
+            }
+        }
+
+        for node in nodes {
+            let (other_system, type_info, other_type_info) = match &node.system.conflict {
+                Some(Conflict::Borrow {
+                    type_info,
+                    other_system,
+                    other_type_info,
+                }) => (other_system, type_info.as_ref(), other_type_info),
+                Some(Conflict::OtherNotSendSync { system, type_info }) => (system, None, type_info),
+                Some(Conflict::NotSendSync(_)) | None => continue,
+            };
+
+            for other_node in nodes.iter().filter(|other_node| {
+                other_node.id != node.id && other_node.system.type_id == other_system.type_id
+            }) {
+                let mut labels = [type_info.map(type_label), Some(type_label(other_type_info))];
+
+                if other_node.batch < node.batch {
+                    labels.reverse();
+                    edges.push((other_node.id, node.id, join_labels(labels)));
+                } else {
+                    edges.push((node.id, other_node.id, join_labels(labels)));
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+struct Node<'a> {
+    id: usize,
+    batch: usize,
+    system: &'a SystemInfo,
+}
+
+impl Node<'_> {
+    fn label(&self) -> String {
+        match &self.system.conflict {
+            Some(Conflict::NotSendSync(type_info)) => {
+                format!(
+                    "{} ({})",
+                    label_text(&*self.system.name),
+                    type_label(type_info)
+                )
+            }
+            _ if self.system.exclusive => {
+                format!("{} (exclusive)", label_text(&*self.system.name))
+            }
+            _ => label_text(&*self.system.name),
+        }
+    }
+}
+
+fn type_label(type_info: &TypeInfo) -> String {
+    match type_info.mutability {
+        Mutability::Shared => format!("&{}", type_info.name),
+        Mutability::Exclusive => format!("&mut {}", type_info.name),
+    }
+}
+
+fn join_labels(labels: [Option<String>; 2]) -> Option<String> {
+    let mut labels = labels.into_iter().flatten();
+    let first = labels.next()?;
+
+    Some(match labels.next() {
+        Some(second) => format!("{} / {}", first, second),
+        None => first,
+    })
+}
+
+/// Debug output of labels made from `&str` is quoted.
+fn label_text(label: &dyn Label) -> String {
+    let text = format!("{:?}", label);
+
+    match text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+    {
+        Some(text) => text.into(),
+        None => text,
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
 }
 
 /// Contains information related to a batch.
@@ -29,6 +299,17 @@ pub struct BatchInfo {
     pub systems: (Option<SystemInfo>, Vec<SystemInfo>),
 }
 
+impl BatchInfo {
+    /// Returns `true` if the batch contains an exclusive system.\
+    /// An exclusive system is always alone in its batch.
+    pub fn is_exclusive(&self) -> bool {
+        self.systems
+            .0
+            .as_ref()
+            .is_some_and(|system_info| system_info.exclusive)
+    }
+}
+
 /// Contains information related to a system.
 #[derive(Clone)]
 pub struct SystemInfo {
@@ -38,6 +319,16 @@ pub struct SystemInfo {
     pub type_id: TypeId,
     #[allow(missing_docs)]
     pub borrow: Vec<TypeInfo>,
+    /// Exclusive systems run alone in their batch.
+    pub exclusive: bool,
+    /// `false` if the system was disabled, either with [`World::set_system_enabled`] or by [`PanicPolicy::DisableSystem`].\
+    /// Only [`World::workload_info`] and [`ScheduledWorkload::workload_info`] reflect changes made after the workload was created.
+    ///
+    /// [`World::set_system_enabled`]: crate::World::set_system_enabled
+    /// [`World::workload_info`]: crate::World::workload_info
+    /// [`ScheduledWorkload::workload_info`]: crate::ScheduledWorkload::workload_info
+    /// [`PanicPolicy::DisableSystem`]: crate::PanicPolicy::DisableSystem
+    pub enabled: bool,
     /// Information explaining why this system could not be part of the previous batch.
     pub conflict: Option<Conflict>,
 }
@@ -47,6 +338,8 @@ impl core::fmt::Debug for SystemInfo {
         f.debug_struct("SystemInfo")
             .field("name", &self.name)
             .field("borrow", &self.borrow)
+            .field("exclusive", &self.exclusive)
+            .field("enabled", &self.enabled)
             .field("conflict", &self.conflict)
             .finish()
     }
@@ -157,6 +450,63 @@ impl core::hash::Hash for TypeInfo {
     }
 }
 
+/// Statistics collected by the profiler for a workload.
+///
+/// See [`World::enable_profiling`](crate::World::enable_profiling).
+#[derive(Debug, Clone, Default)]
+pub struct WorkloadStats {
+    /// Number of times the workload ran.
+    pub runs: u64,
+    /// Statistics of each system, in the order they first ran or were skipped.
+    pub systems: Vec<SystemStats>,
+}
+
+/// Statistics collected by the profiler for a system.
+#[derive(Debug, Clone)]
+pub struct SystemStats {
+    #[allow(missing_docs)]
+    pub name: Box<dyn Label>,
+    /// Number of times the system ran.
+    pub calls: u64,
+    /// Number of times the system was skipped by its `run_if`.
+    pub skipped: u64,
+    /// Wall time of all calls combined.
+    pub total_time: Duration,
+    /// Wall time of the last call.
+    pub last_time: Duration,
+    /// Wall time of the slowest call.
+    pub max_time: Duration,
+}
+
+impl SystemStats {
+    #[cfg(feature = "std")]
+    pub(crate) fn new(name: Box<dyn Label>) -> SystemStats {
+        SystemStats {
+            name,
+            calls: 0,
+            skipped: 0,
+            total_time: Duration::ZERO,
+            last_time: Duration::ZERO,
+            max_time: Duration::ZERO,
+        }
+    }
+    #[cfg(feature = "std")]
+    pub(crate) fn record(&mut self, time: Duration) {
+        self.calls += 1;
+        self.total_time += time;
+        self.last_time = time;
+        self.max_time = self.max_time.max(time);
+    }
+    /// Average wall time of a call.
+    pub fn mean_time(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos((self.total_time.as_nanos() / self.calls as u128) as u64)
+        }
+    }
+}
+
 /// Contains a list of workloads, their systems and which storages these systems borrow.
 #[allow(clippy::type_complexity)]
 #[derive(Debug)]
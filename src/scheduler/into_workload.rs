@@ -184,6 +184,10 @@ where
                 require_before: DedupedLabels::new(),
                 require_after: DedupedLabels::new(),
                 barriers: Vec::new(),
+                fixed_timestep: None,
+                nested_fixed_timestep: None,
+                sets: Vec::new(),
+                panic_policy: None,
             }
         }
     }
@@ -226,6 +230,10 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    fixed_timestep: None,
+                    nested_fixed_timestep: None,
+                    sets: Vec::new(),
+                    panic_policy: None,
                 };
 
                 $(
@@ -256,6 +264,10 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    fixed_timestep: None,
+                    nested_fixed_timestep: None,
+                    sets: Vec::new(),
+                    panic_policy: None,
                 };
 
                 let mut sequential_tags = Vec::new();
@@ -5,8 +5,9 @@ use crate::scheduler::label::{SystemLabel, WorkloadLabel};
 use crate::scheduler::{TypeInfo, WorkloadSystem};
 use crate::storage::StorageId;
 use crate::type_id::TypeId;
+use crate::views::SystemLocals;
 use crate::{error, AsLabel, Workload};
-use crate::{Label, World};
+use crate::{AllStoragesViewMut, Label, World};
 use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -57,6 +58,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            exclusive: false,
+            panic_policy: None,
         })
     }
     fn label(&self) -> Box<dyn Label> {
@@ -77,6 +80,64 @@ where
     }
 }
 
+/// Marker for systems taking `&mut AllStorages`.
+///
+/// Exclusive systems run alone: all systems added before them in the workload run in earlier batches and all systems added after them run in later batches.\
+/// They get `&mut AllStorages`, not `&mut World`. Workloads are run from a shared `&World` so the scheduler and the other workloads stay reachable.
+///
+/// Exclusive systems can't return anything, in particular they can't be used to create a [`Workload`].
+pub struct ExclusiveSystem;
+
+impl<F> IntoWorkloadSystem<ExclusiveSystem, ()> for F
+where
+    F: 'static + Send + Sync + Fn(&mut AllStorages),
+{
+    fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let mut borrows = Vec::new();
+        AllStoragesViewMut::borrow_info(&mut borrows);
+
+        Ok(WorkloadSystem {
+            borrow_constraints: borrows,
+            tracking_to_enable: Vec::new(),
+            system_fn: Box::new(move |world: &World| {
+                let mut all_storages = world.borrow::<AllStoragesViewMut<'_>>()?;
+
+                (self)(&mut all_storages);
+
+                Ok(())
+            }),
+            type_id: TypeId::of::<F>(),
+            display_name: Box::new(type_name::<F>()),
+            generator: Box::new(|constraints| {
+                AllStoragesViewMut::borrow_info(constraints);
+
+                TypeId::of::<F>()
+            }),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            tags: vec![Box::new(SystemLabel {
+                type_id: TypeId::of::<F>(),
+                name: type_name::<F>().as_label(),
+            })],
+            run_if: None,
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+            exclusive: true,
+            panic_policy: None,
+        })
+    }
+    fn label(&self) -> Box<dyn Label> {
+        Box::new(SystemLabel {
+            type_id: TypeId::of::<F>(),
+            name: type_name::<F>().as_label(),
+        })
+    }
+    fn call(&self) {
+        unreachable!()
+    }
+}
+
 impl IntoWorkloadSystem<WorkloadSystem, ()> for WorkloadSystem {
     fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
         Ok(self)
@@ -144,6 +205,11 @@ macro_rules! impl_into_workload_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
+                let mut locals = SystemLocals::new();
+                $(
+                    $type::world_init_locals(&mut locals);
+                )+
+
                 let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
@@ -151,7 +217,8 @@ macro_rules! impl_into_workload_system {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
                         let last_run = last_run.swap(current, Ordering::Acquire);
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+)))
+                        let mut locals = locals.cursor();
+                        Ok(drop((&&self)($($type::world_borrow_with_locals(&world, &mut locals, Some(last_run), current)?),+)))
                     }),
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(type_name::<Func>()),
@@ -172,6 +239,8 @@ macro_rules! impl_into_workload_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    exclusive: false,
+                    panic_policy: None,
                 })
             }
             fn label(&self) -> Box<dyn Label> {
@@ -6,6 +6,7 @@ use crate::scheduler::label::SystemLabel;
 use crate::scheduler::{TypeInfo, WorkloadSystem};
 use crate::storage::StorageId;
 use crate::type_id::TypeId;
+use crate::views::SystemLocals;
 use crate::World;
 use crate::{error, AsLabel};
 use alloc::boxed::Box;
@@ -71,6 +72,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            exclusive: false,
+            panic_policy: None,
         })
     }
     #[cfg(not(feature = "std"))]
@@ -102,6 +105,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            exclusive: false,
+            panic_policy: None,
         })
     }
 }
@@ -158,6 +163,11 @@ macro_rules! impl_into_workload_try_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
+                let mut locals = SystemLocals::new();
+                $(
+                    $type::world_init_locals(&mut locals);
+                )+
+
                 let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
@@ -165,7 +175,8 @@ macro_rules! impl_into_workload_try_system {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
                         let last_run = last_run.swap(current, Ordering::Acquire);
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
+                        let mut locals = locals.cursor();
+                        Ok(drop((&&self)($($type::world_borrow_with_locals(&world, &mut locals, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(type_name::<Func>()),
@@ -186,6 +197,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    exclusive: false,
+                    panic_policy: None,
                 })
             }
             #[cfg(not(feature = "std"))]
@@ -229,6 +242,11 @@ macro_rules! impl_into_workload_try_system {
                     $type::enable_tracking(&mut tracking_to_enable);
                 )+
 
+                let mut locals = SystemLocals::new();
+                $(
+                    $type::world_init_locals(&mut locals);
+                )+
+
                 let last_run = AtomicU32::new(0);
                 Ok(WorkloadSystem {
                     borrow_constraints: borrows,
@@ -236,7 +254,8 @@ macro_rules! impl_into_workload_try_system {
                     system_fn: Box::new(move |world: &World| {
                         let current = world.get_current();
                         let last_run = last_run.swap(current, Ordering::Acquire);
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
+                        let mut locals = locals.cursor();
+                        Ok(drop((&&self)($($type::world_borrow_with_locals(&world, &mut locals, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
                     }),
                     type_id: TypeId::of::<Func>(),
                     display_name: Box::new(type_name::<Func>()),
@@ -257,6 +276,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    exclusive: false,
+                    panic_policy: None,
                 })
             }
         }
@@ -1,31 +1,44 @@
+mod fixed_timestep;
 pub mod info;
 mod into_workload;
 mod into_workload_run_if;
 mod into_workload_system;
 mod into_workload_try_system;
 mod label;
+mod panic;
+mod profiler;
 mod system;
 mod system_modificator;
+mod system_set;
 mod workload;
 mod workload_modificator;
 
+pub use fixed_timestep::{FixedTimestepAlpha, Time};
 pub use into_workload::IntoWorkload;
 pub use into_workload_system::IntoWorkloadSystem;
 pub use into_workload_try_system::IntoWorkloadTrySystem;
 pub use label::{AsLabel, Label};
+pub use panic::PanicPolicy;
 pub use system::WorkloadSystem;
 pub use system_modificator::SystemModificator;
+pub use system_set::SystemSet;
 pub use workload::{ScheduledWorkload, Workload};
 pub use workload_modificator::WorkloadModificator;
 
+pub(crate) use fixed_timestep::FixedTimestep;
 pub(crate) use info::TypeInfo;
+pub(crate) use panic::{catch_system_panic, CaughtPanic};
+pub(crate) use profiler::{profile_system, record_run, record_skip, Profiler};
 
 use crate::error;
+use crate::scheduler::info::WorkloadInfo;
+use crate::scheduler::label::SystemLabel;
 use crate::scheduler::system::WorkloadRunIfFn;
 use crate::type_id::TypeId;
 use crate::World;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
 use hashbrown::HashMap;
 
 /// List of indexes into both systems and system_names
@@ -41,6 +54,99 @@ pub(super) struct Batches {
     pub(super) sequential_run_if:
         Vec<Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>>,
     pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+    pub(super) fixed_timestep: Option<FixedTimestep>,
+    /// Indexed like `sequential`
+    pub(super) panic_policies: Vec<Option<PanicPolicy>>,
+    /// Indexed like `sequential`, disabled systems don't run
+    pub(super) disabled: Vec<AtomicBool>,
+    /// Indexed like `sequential`, set when a system panics with [`PanicPolicy::DisableSystem`]
+    pub(super) panicked: Vec<AtomicBool>,
+}
+
+impl Batches {
+    /// Returns `true` if the system at `system_position` in `sequential` can run.
+    pub(super) fn is_enabled(&self, system_position: usize) -> bool {
+        [&self.disabled, &self.panicked].iter().all(|flags| {
+            !flags
+                .get(system_position)
+                .is_some_and(|flag| flag.load(Ordering::Acquire))
+        })
+    }
+    /// Enables or disables all occurrences of the system in the workload.\
+    /// Enabling a system also lets it run again after a panic.
+    fn set_system_enabled(&self, system_index: usize, enabled: bool) {
+        for ((disabled, panicked), _) in self
+            .disabled
+            .iter()
+            .zip(&self.panicked)
+            .zip(&self.sequential)
+            .filter(|(_, &index)| index == system_index)
+        {
+            disabled.store(!enabled, Ordering::Release);
+
+            if enabled {
+                panicked.store(false, Ordering::Release);
+            }
+        }
+    }
+    /// Returns `true` if no occurrence of the system is disabled.
+    fn is_system_enabled(&self, system_index: usize) -> bool {
+        self.sequential
+            .iter()
+            .enumerate()
+            .filter(|(_, &index)| index == system_index)
+            .all(|(system_position, _)| self.is_enabled(system_position))
+    }
+}
+
+/// Returns the index of the system identified by `label`, either its function or its name.
+fn find_system(
+    label: &dyn Label,
+    lookup_table: &HashMap<TypeId, usize>,
+    system_names: &[Box<dyn Label>],
+) -> Option<usize> {
+    if let Some(system_label) = label.as_any().downcast_ref::<SystemLabel>() {
+        lookup_table.get(&system_label.type_id).copied()
+    } else {
+        system_names.iter().position(|name| **name == *label)
+    }
+}
+
+/// Enables or disables the system identified by `label` in all `workloads`.
+pub(super) fn set_system_enabled(
+    label: &dyn Label,
+    enabled: bool,
+    lookup_table: &HashMap<TypeId, usize>,
+    system_names: &[Box<dyn Label>],
+    workloads: &HashMap<Box<dyn Label>, Batches>,
+) -> Result<(), error::SetSystemEnabled> {
+    let system_index = find_system(label, lookup_table, system_names)
+        .ok_or(error::SetSystemEnabled::MissingSystem)?;
+
+    for batches in workloads.values() {
+        batches.set_system_enabled(system_index, enabled);
+    }
+
+    Ok(())
+}
+
+/// Updates whether the systems of `workload_info` are enabled.
+pub(super) fn refresh_enabled(
+    workload_info: &mut WorkloadInfo,
+    batches: &Batches,
+    lookup_table: &HashMap<TypeId, usize>,
+) {
+    for system_info in workload_info.batch_info.iter_mut().flat_map(|batch_info| {
+        batch_info
+            .systems
+            .0
+            .iter_mut()
+            .chain(&mut batch_info.systems.1)
+    }) {
+        if let Some(&system_index) = lookup_table.get(&system_info.type_id) {
+            system_info.enabled = batches.is_system_enabled(system_index);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +184,10 @@ pub(crate) struct Scheduler {
     /// workload name to list of "batches"
     pub(crate) workloads: HashMap<Box<dyn Label>, Batches>,
     pub(crate) default: Box<dyn Label>,
+    /// Only present when profiling is enabled
+    pub(crate) profiler: Option<Profiler>,
+    /// workload name to the information returned when it was added
+    pub(crate) workload_infos: HashMap<Box<dyn Label>, WorkloadInfo>,
 }
 
 impl Default for Scheduler {
@@ -89,6 +199,8 @@ impl Default for Scheduler {
             lookup_table: HashMap::new(),
             workloads: HashMap::new(),
             default: Box::new(""),
+            profiler: None,
+            workload_infos: HashMap::new(),
         }
     }
 }
@@ -128,9 +240,38 @@ impl Scheduler {
                 self.default = new.clone();
             }
 
+            if let Some(mut workload_info) = self.workload_infos.remove(old) {
+                workload_info.name = new.clone();
+                self.workload_infos.insert(new.clone(), workload_info);
+            }
+
             self.workloads.insert(new, batches);
         }
     }
+    pub(crate) fn set_system_enabled(
+        &self,
+        label: &dyn Label,
+        enabled: bool,
+    ) -> Result<(), error::SetSystemEnabled> {
+        set_system_enabled(
+            label,
+            enabled,
+            &self.lookup_table,
+            &self.system_names,
+            &self.workloads,
+        )
+    }
+    pub(crate) fn workload_info(&self, name: &dyn Label) -> Option<WorkloadInfo> {
+        let mut workload_info = self.workload_infos.get(name)?.clone();
+
+        refresh_enabled(
+            &mut workload_info,
+            &self.workloads[name],
+            &self.lookup_table,
+        );
+
+        Some(workload_info)
+    }
 }
 
 impl core::fmt::Debug for Scheduler {
@@ -0,0 +1,131 @@
+use crate::error;
+use crate::scheduler::{Batches, Label};
+#[cfg(feature = "std")]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use core::sync::atomic::Ordering;
+#[cfg(feature = "std")]
+use std::panic::{catch_unwind, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+/// What to do when a system of the workload panics.
+///
+/// In all cases the panic is caught, the views borrowed by the system are released
+/// and the panic is returned as [`RunWorkload::Panic`].
+///
+/// [`RunWorkload::Panic`]: crate::error::RunWorkload::Panic
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Stops the workload once the systems running in parallel with the panicking one are done.
+    Abort,
+    /// Skips the panicking system and runs the rest of the workload.\
+    /// The first panic is returned once the workload is done.
+    SkipSystem,
+    /// Like [`SkipSystem`](PanicPolicy::SkipSystem) but the system won't run again in this workload.
+    DisableSystem,
+}
+
+/// First panic caught by a [`PanicPolicy`] that didn't stop the workload.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(crate) struct CaughtPanic(Mutex<Option<error::RunWorkload>>);
+
+/// Panics can't be caught without `std`.
+#[cfg(not(feature = "std"))]
+#[derive(Default)]
+pub(crate) struct CaughtPanic;
+
+#[cfg(feature = "std")]
+impl CaughtPanic {
+    fn store(&self, err: error::RunWorkload) {
+        let mut caught = self.0.lock().unwrap_or_else(|err| err.into_inner());
+
+        if caught.is_none() {
+            *caught = Some(err);
+        }
+    }
+    /// Returns the first panic caught, if any.
+    pub(crate) fn into_result(self) -> Result<(), error::RunWorkload> {
+        match self.0.into_inner().unwrap_or_else(|err| err.into_inner()) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl CaughtPanic {
+    /// Returns the first panic caught, if any.
+    pub(crate) fn into_result(self) -> Result<(), error::RunWorkload> {
+        Ok(())
+    }
+}
+
+/// Runs `system` and handles its panic following the system's [`PanicPolicy`].
+///
+/// `system_position` is the position of the system in `batches.sequential`.
+#[cfg(feature = "std")]
+pub(crate) fn catch_system_panic(
+    batches: &Batches,
+    system_position: usize,
+    system_name: &dyn Label,
+    caught_panic: &CaughtPanic,
+    system: impl FnOnce() -> Result<(), error::RunWorkload>,
+) -> Result<(), error::RunWorkload> {
+    let policy = match batches
+        .panic_policies
+        .get(system_position)
+        .copied()
+        .flatten()
+    {
+        Some(policy) => policy,
+        None => return system(),
+    };
+
+    // The views borrowed by the system are dropped while unwinding
+    let payload = match catch_unwind(AssertUnwindSafe(system)) {
+        Ok(result) => return result,
+        Err(payload) => payload,
+    };
+
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Box<dyn Any>")
+    };
+    let err = error::RunWorkload::Panic((system_name.dyn_clone(), message));
+
+    match policy {
+        PanicPolicy::Abort => Err(err),
+        PanicPolicy::SkipSystem => {
+            caught_panic.store(err);
+
+            Ok(())
+        }
+        PanicPolicy::DisableSystem => {
+            if let Some(panicked) = batches.panicked.get(system_position) {
+                panicked.store(true, Ordering::Release);
+            }
+            caught_panic.store(err);
+
+            Ok(())
+        }
+    }
+}
+
+/// Runs `system` and handles its panic following the system's [`PanicPolicy`].
+///
+/// `system_position` is the position of the system in `batches.sequential`.
+#[cfg(not(feature = "std"))]
+pub(crate) fn catch_system_panic(
+    _batches: &Batches,
+    _system_position: usize,
+    _system_name: &dyn Label,
+    _caught_panic: &CaughtPanic,
+    system: impl FnOnce() -> Result<(), error::RunWorkload>,
+) -> Result<(), error::RunWorkload> {
+    system()
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "std")]
+use crate::scheduler::info::{SystemStats, WorkloadStats};
+use crate::scheduler::Label;
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Collects statistics about the workloads run by the `Scheduler`.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub(crate) struct Profiler {
+    workloads: Mutex<HashMap<Box<dyn Label>, WorkloadProfile>>,
+}
+
+/// Wall time can't be measured without `std`, this type can't be created.
+#[cfg(not(feature = "std"))]
+pub(crate) enum Profiler {}
+
+/// Statistics of a workload, systems are identified by their index in the `Scheduler`.
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct WorkloadProfile {
+    runs: u64,
+    systems: Vec<(usize, SystemStats)>,
+}
+
+#[cfg(feature = "std")]
+impl WorkloadProfile {
+    fn system(&mut self, system_index: usize, system_name: &dyn Label) -> &mut SystemStats {
+        let position = match self
+            .systems
+            .iter()
+            .position(|(index, _)| *index == system_index)
+        {
+            Some(position) => position,
+            None => {
+                self.systems
+                    .push((system_index, SystemStats::new(system_name.dyn_clone())));
+
+                self.systems.len() - 1
+            }
+        };
+
+        &mut self.systems[position].1
+    }
+}
+
+#[cfg(feature = "std")]
+impl Profiler {
+    fn with_workload<R>(
+        &self,
+        workload_name: &dyn Label,
+        f: impl FnOnce(&mut WorkloadProfile) -> R,
+    ) -> R {
+        let mut workloads = self.workloads.lock().unwrap_or_else(|err| err.into_inner());
+
+        if let Some(profile) = workloads.get_mut(workload_name) {
+            f(profile)
+        } else {
+            f(workloads.entry(workload_name.dyn_clone()).or_default())
+        }
+    }
+    /// Returns the statistics of the workload.
+    pub(crate) fn workload_stats(&self, workload_name: &dyn Label) -> Option<WorkloadStats> {
+        let workloads = self.workloads.lock().unwrap_or_else(|err| err.into_inner());
+
+        workloads.get(workload_name).map(|profile| WorkloadStats {
+            runs: profile.runs,
+            systems: profile
+                .systems
+                .iter()
+                .map(|(_, stats)| stats.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Records a run of the workload.
+#[cfg(feature = "std")]
+pub(crate) fn record_run(profiler: Option<&Profiler>, workload_name: &dyn Label) {
+    if let Some(profiler) = profiler {
+        profiler.with_workload(workload_name, |profile| profile.runs += 1);
+    }
+}
+
+/// Records a run of the workload.
+#[cfg(not(feature = "std"))]
+pub(crate) fn record_run(_profiler: Option<&Profiler>, _workload_name: &dyn Label) {}
+
+/// Records a system that didn't run because of its `run_if`.
+#[cfg(feature = "std")]
+pub(crate) fn record_skip(
+    profiler: Option<&Profiler>,
+    workload_name: &dyn Label,
+    system_index: usize,
+    system_name: &dyn Label,
+) {
+    if let Some(profiler) = profiler {
+        profiler.with_workload(workload_name, |profile| {
+            profile.system(system_index, system_name).skipped += 1;
+        });
+    }
+}
+
+/// Records a system that didn't run because of its `run_if`.
+#[cfg(not(feature = "std"))]
+pub(crate) fn record_skip(
+    _profiler: Option<&Profiler>,
+    _workload_name: &dyn Label,
+    _system_index: usize,
+    _system_name: &dyn Label,
+) {
+}
+
+/// Runs `system` and records its wall time.
+#[cfg(feature = "std")]
+pub(crate) fn profile_system<R>(
+    profiler: Option<&Profiler>,
+    workload_name: &dyn Label,
+    system_index: usize,
+    system_name: &dyn Label,
+    system: impl FnOnce() -> R,
+) -> R {
+    if let Some(profiler) = profiler {
+        let start = Instant::now();
+        let result = system();
+        let time = start.elapsed();
+
+        profiler.with_workload(workload_name, |profile| {
+            profile.system(system_index, system_name).record(time);
+        });
+
+        result
+    } else {
+        system()
+    }
+}
+
+/// Runs `system` and records its wall time.
+#[cfg(not(feature = "std"))]
+pub(crate) fn profile_system<R>(
+    _profiler: Option<&Profiler>,
+    _workload_name: &dyn Label,
+    _system_index: usize,
+    _system_name: &dyn Label,
+    system: impl FnOnce() -> R,
+) -> R {
+    system()
+}
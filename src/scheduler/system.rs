@@ -4,6 +4,7 @@ use crate::error;
 use crate::info::DedupedLabels;
 use crate::scheduler::label::Label;
 use crate::scheduler::workload::Workload;
+use crate::scheduler::PanicPolicy;
 use crate::type_id::TypeId;
 use crate::world::World;
 use alloc::boxed::Box;
@@ -51,6 +52,10 @@ pub struct WorkloadSystem {
     pub(crate) require_in_workload: DedupedLabels,
     pub(crate) require_before: DedupedLabels,
     pub(crate) require_after: DedupedLabels,
+    /// Runs alone in its batch, no other system runs in parallel with it.
+    pub(crate) exclusive: bool,
+    /// Policy of the workload the system was added to.
+    pub(crate) panic_policy: Option<PanicPolicy>,
 }
 
 impl Extend<WorkloadSystem> for Workload {
@@ -0,0 +1,121 @@
+use crate::scheduler::info::DedupedLabels;
+use crate::scheduler::into_workload_run_if::IntoWorkloadRunIf;
+use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
+use crate::scheduler::{AsLabel, Label, WorkloadSystem};
+use crate::World;
+use alloc::boxed::Box;
+use core::ops::Not;
+
+/// Configuration shared by all systems with the same tag.
+///
+/// Added to a workload with [`Workload::configure_set`].\
+/// Every system of the workload tagged with the set's tag inherits its ordering requirements and run conditions.
+///
+/// ### Example
+/// ```
+/// use shipyard::{SystemModificator, SystemSet, Workload, World};
+///
+/// fn physics() {}
+/// fn collisions() {}
+/// fn render() {}
+///
+/// let world = World::new();
+///
+/// Workload::new("Frame")
+///     .with_system(render.tag("render"))
+///     .with_system(physics.tag("physics"))
+///     .with_system(collisions.tag("physics"))
+///     .configure_set(SystemSet::new("physics").before_all("render"))
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Frame").unwrap();
+/// ```
+///
+/// [`Workload::configure_set`]: crate::Workload::configure_set
+pub struct SystemSet {
+    pub(super) tag: Box<dyn Label>,
+    pub(super) before_all: DedupedLabels,
+    pub(super) after_all: DedupedLabels,
+    pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+}
+
+impl SystemSet {
+    /// Creates a new [`SystemSet`] grouping all systems tagged with `tag`.
+    pub fn new<T>(tag: impl AsLabel<T>) -> SystemSet {
+        SystemSet {
+            tag: tag.as_label(),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            run_if: None,
+        }
+    }
+    /// Runs all systems of the set before systems tagged with `other`.
+    pub fn before_all<T>(mut self, other: impl AsLabel<T>) -> SystemSet {
+        self.before_all.add(other);
+
+        self
+    }
+    /// Runs all systems of the set after systems tagged with `other`.
+    pub fn after_all<T>(mut self, other: impl AsLabel<T>) -> SystemSet {
+        self.after_all.add(other);
+
+        self
+    }
+    /// Only runs the systems of the set if the function evaluates to `true`.\
+    /// The function is evaluated once per system.
+    #[track_caller]
+    pub fn run_if<RunB, Run: IntoWorkloadRunIf<RunB>>(mut self, run_if: Run) -> SystemSet {
+        let run_if = run_if.into_workload_run_if().unwrap();
+
+        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
+            Some(Box::new(move |world: &World| {
+                Ok(prev_run_if.run(world)? && run_if.run(world)?)
+            }))
+        } else {
+            Some(run_if)
+        };
+
+        self
+    }
+    /// Do not run the systems of the set if the function evaluates to `true`.\
+    /// The function is evaluated once per system.
+    #[track_caller]
+    pub fn skip_if<RunB, Run: IntoWorkloadRunIf<RunB>>(mut self, should_skip: Run) -> SystemSet {
+        let mut should_skip = should_skip.into_workload_run_if().unwrap();
+
+        should_skip = Box::new(move |world: &World| should_skip.run(world).map(Not::not));
+
+        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
+            Some(Box::new(move |world: &World| {
+                Ok(prev_run_if.run(world)? && should_skip.run(world)?)
+            }))
+        } else {
+            Some(should_skip)
+        };
+
+        self
+    }
+    /// Adds the set's requirements and run condition to `system` if it is part of the set.
+    pub(super) fn apply(&self, system: &mut WorkloadSystem) {
+        if !system.tags.contains(&self.tag) {
+            return;
+        }
+
+        system.before_all.extend(&self.before_all);
+        system.after_all.extend(&self.after_all);
+
+        if let Some(set_run_if) = &self.run_if {
+            system.run_if = match system.run_if.take() {
+                None => Some(set_run_if.clone().to_non_clone()),
+                Some(system_run_if) => {
+                    let set_run_if = set_run_if.clone();
+
+                    Some(Box::new(move |world| {
+                        Ok(set_run_if.run(world)? && (system_run_if)(world)?)
+                    }))
+                }
+            };
+        }
+    }
+}
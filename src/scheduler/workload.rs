@@ -1,12 +1,16 @@
 use crate::all_storages::AllStorages;
 use crate::borrow::Mutability;
 use crate::component::{Component, Unique};
+use crate::scheduler::fixed_timestep::DEFAULT_MAX_STEPS;
 use crate::scheduler::info::{
     BatchInfo, Conflict, DedupedLabels, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
 };
 use crate::scheduler::label::{SystemLabel, WorkloadLabel};
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
-use crate::scheduler::{AsLabel, Batches, IntoWorkloadTrySystem, Label, Scheduler, WorkloadSystem};
+use crate::scheduler::{
+    refresh_enabled, set_system_enabled, AsLabel, Batches, FixedTimestep, IntoWorkloadTrySystem,
+    Label, PanicPolicy, Scheduler, SystemSet, WorkloadSystem,
+};
 use crate::storage::StorageId;
 use crate::type_id::TypeId;
 use crate::unique::UniqueStorage;
@@ -20,6 +24,8 @@ use alloc::vec::Vec;
 use core::any::type_name;
 #[cfg(not(feature = "std"))]
 use core::any::Any;
+use core::sync::atomic::AtomicBool;
+use core::time::Duration;
 use hashbrown::HashMap;
 #[cfg(feature = "std")]
 use std::error::Error;
@@ -39,11 +45,11 @@ pub struct ScheduledWorkload {
     #[allow(unused)]
     system_generators: Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     // system's `TypeId` to an index into both systems and system_names
-    #[allow(unused)]
     lookup_table: HashMap<TypeId, usize>,
     tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
     /// workload name to list of "batches"
     workloads: HashMap<Box<dyn Label>, Batches>,
+    workload_info: WorkloadInfo,
 }
 
 impl ScheduledWorkload {
@@ -63,6 +69,8 @@ impl ScheduledWorkload {
             &self.system_names,
             &self.workloads[&self.name],
             &self.name,
+            None,
+            world.is_deterministic(),
         )
     }
 
@@ -88,6 +96,43 @@ impl ScheduledWorkload {
 
         Ok(())
     }
+    /// Enables or disables a system of the workload.\
+    /// `label` can be the system's function or its name.
+    ///
+    /// Disabled systems are skipped, as if their `run_if` returned `false`.
+    ///
+    /// ### Errors
+    ///
+    /// - No system with this label in the workload.
+    pub fn set_system_enabled<T>(
+        &self,
+        label: impl AsLabel<T>,
+        enabled: bool,
+    ) -> Result<(), error::SetSystemEnabled> {
+        let label = label.as_label();
+
+        set_system_enabled(
+            &*label,
+            enabled,
+            &self.lookup_table,
+            &self.system_names,
+            &self.workloads,
+        )
+    }
+    /// Returns the workload's information with the up to date [`enabled`] state of its systems.
+    ///
+    /// [`enabled`]: crate::info::SystemInfo::enabled
+    pub fn workload_info(&self) -> WorkloadInfo {
+        let mut workload_info = self.workload_info.clone();
+
+        refresh_enabled(
+            &mut workload_info,
+            &self.workloads[&self.name],
+            &self.lookup_table,
+        );
+
+        workload_info
+    }
 }
 
 impl World {
@@ -134,6 +179,11 @@ pub struct Workload {
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) fixed_timestep: Option<(Duration, u32)>,
+    /// Name of a nested workload with a fixed timestep, they're not supported.
+    pub(super) nested_fixed_timestep: Option<Box<dyn Label>>,
+    pub(super) sets: Vec<SystemSet>,
+    pub(super) panic_policy: Option<PanicPolicy>,
 }
 
 impl Workload {
@@ -192,6 +242,10 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            fixed_timestep: None,
+            nested_fixed_timestep: None,
+            sets: Vec::new(),
+            panic_policy: None,
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.  
@@ -214,6 +268,12 @@ impl Workload {
                 .drain(..)
                 .map(|barrier| barrier + systems_len),
         );
+        self.sets.append(&mut other.sets);
+        self.nested_fixed_timestep = self
+            .nested_fixed_timestep
+            .take()
+            .or_else(|| other.nested_fixed_timestep.take())
+            .or_else(|| other.fixed_timestep.map(|_| other.name.clone()));
 
         self.append(&mut other)
     }
@@ -231,6 +291,7 @@ impl Workload {
             };
 
             system.tags.extend(self.tags.iter().cloned());
+            system.panic_policy = system.panic_policy.or(self.panic_policy);
 
             system.before_all.extend(self.before_all.iter().cloned());
             system.after_all.extend(self.after_all.iter().cloned());
@@ -251,6 +312,10 @@ impl Workload {
     }
     /// Adds a system to the workload being created.
     ///
+    /// Systems taking `&mut AllStorages` are exclusive, they run alone in their batch.\
+    /// All systems added before them run in earlier batches and all systems added after them in later batches.\
+    /// Exclusive systems get `&mut AllStorages` rather than `&mut World`, the scheduler is still borrowed while they run.
+    ///
     /// ### Example:
     /// ```
     /// use shipyard::{Component, EntitiesViewMut, IntoIter, View, ViewMut, Workload, World};
@@ -420,6 +485,7 @@ impl Workload {
     /// - Scheduler borrow failed.
     /// - Workload with an identical name already present.
     /// - Nested workload is not present in `world`.
+    /// - Nested workload has a [fixed timestep](Workload::fixed_timestep).
     /// - [`AllStorages`] borrow failed.
     /// - Storage borrow failed.
     #[allow(clippy::blocks_in_if_conditions)]
@@ -431,6 +497,8 @@ impl Workload {
             lookup_table,
             workloads,
             default,
+            profiler: _,
+            workload_infos,
         } = &mut *world
             .scheduler
             .borrow_mut()
@@ -449,6 +517,8 @@ impl Workload {
             default,
         )?;
 
+        workload_infos.insert(workload_info.name.clone(), workload_info.clone());
+
         let all_storages = world
             .all_storages()
             .map_err(|_| error::AddWorkload::TrackingAllStoragesBorrow)?;
@@ -508,6 +578,11 @@ impl Workload {
             lookup_table: HashMap::new(),
             tracking_to_enable: Vec::new(),
             workloads: HashMap::new(),
+            workload_info: WorkloadInfo {
+                name: self.name.clone(),
+                batch_info: Vec::new(),
+                orderings: Vec::new(),
+            },
         };
 
         let mut default: Box<dyn Label> = Box::new("");
@@ -523,12 +598,160 @@ impl Workload {
             &mut default,
         )?;
 
+        workload.workload_info = workload_info.clone();
+
         Ok((workload, workload_info))
     }
     /// Stop parallelism between systems before and after the barrier.
     pub fn with_barrier(mut self) -> Self {
         self.barriers.push(self.systems.len());
 
+        self
+    }
+    /// Configures all systems tagged with the set's tag, including systems of nested workloads.
+    ///
+    /// The set's `before_all`/`after_all` requirements and run conditions are added to each of its systems
+    /// when the workload is added to the `World`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{SystemModificator, SystemSet, Unique, UniqueView, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Paused(bool);
+    ///
+    /// fn input() {}
+    /// fn physics() {}
+    /// fn collisions() {}
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Paused(false));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(physics.tag("simulation"))
+    ///     .with_system(collisions.tag("simulation"))
+    ///     .with_system(input.tag("input"))
+    ///     .configure_set(
+    ///         SystemSet::new("simulation")
+    ///             .after_all("input")
+    ///             .skip_if(|paused: UniqueView<Paused>| paused.0),
+    ///     )
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Frame").unwrap();
+    /// ```
+    pub fn configure_set(mut self, set: SystemSet) -> Self {
+        self.sets.push(set);
+
+        self
+    }
+    /// Catches panics of the workload's systems and handles them following `policy`.
+    ///
+    /// Without a policy, a panicking system unwinds through [`World::run_workload`].\
+    /// The policy applies to the systems of this workload only, a merged workload keeps its own policy.\
+    /// Merged systems without a policy use the one of the workload they are merged into.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, PanicPolicy, Workload, World};
+    ///
+    /// fn faulty() {
+    ///     panic!("Oops");
+    /// }
+    ///
+    /// fn other() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Faulty")
+    ///     .with_system(faulty)
+    ///     .with_system(other)
+    ///     .on_panic(PanicPolicy::DisableSystem)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     world.run_workload("Faulty"),
+    ///     Err(error::RunWorkload::Panic(_))
+    /// ));
+    /// // `faulty` is disabled
+    /// assert!(world.run_workload("Faulty").is_ok());
+    /// ```
+    ///
+    /// [`World::run_workload`]: crate::World::run_workload
+    #[cfg(feature = "std")]
+    pub fn on_panic(mut self, policy: PanicPolicy) -> Self {
+        self.panic_policy = Some(policy);
+
+        self
+    }
+    /// Runs the workload at a fixed rate, independent of how often it is called.
+    ///
+    /// Each call accumulates the time elapsed since the previous call, read from the [`Time`] unique,
+    /// and runs the workload once per full `step`, potentially zero times.\
+    /// The number of steps per call is capped to 5 by default to not fall further behind when steps are slow,
+    /// use [`max_catch_up_steps`](Workload::max_catch_up_steps) to modify it.\
+    /// After each call, the leftover time is stored in the [`FixedTimestepAlpha`] unique.
+    ///
+    /// `run_if`/`skip_if` are evaluated once per call, time does not accumulate while the workload is skipped.\
+    /// A workload with a fixed timestep can't be nested in another one, adding or building the outer workload returns an error.
+    ///
+    /// ### Panics
+    ///
+    /// - `step` is zero.
+    ///
+    /// ### Example
+    /// ```
+    /// use core::time::Duration;
+    /// use shipyard::{FixedTimestepAlpha, Time, UniqueView, UniqueViewMut, Unique, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Steps(u32);
+    ///
+    /// fn physics(mut steps: UniqueViewMut<Steps>) {
+    ///     steps.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Time::new());
+    /// world.add_unique(Steps(0));
+    ///
+    /// Workload::new("Physics")
+    ///     .with_system(physics)
+    ///     .fixed_timestep(Duration::from_millis(10))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run(|mut time: UniqueViewMut<Time>| time.advance(Duration::from_millis(25)));
+    /// world.run_workload("Physics").unwrap();
+    ///
+    /// assert_eq!(world.borrow::<UniqueView<Steps>>().unwrap().0, 2);
+    /// assert_eq!(world.borrow::<UniqueView<FixedTimestepAlpha>>().unwrap().0, 0.5);
+    /// ```
+    ///
+    /// [`Time`]: crate::Time
+    /// [`FixedTimestepAlpha`]: crate::FixedTimestepAlpha
+    #[track_caller]
+    pub fn fixed_timestep(mut self, step: Duration) -> Self {
+        assert!(!step.is_zero(), "Fixed timestep cannot be zero.");
+
+        let max_steps = self
+            .fixed_timestep
+            .map_or(DEFAULT_MAX_STEPS, |(_, max_steps)| max_steps);
+        self.fixed_timestep = Some((step, max_steps));
+
+        self
+    }
+    /// Sets the maximum number of steps a [fixed timestep](Workload::fixed_timestep) workload can run per call.\
+    /// Time that would require more steps is dropped.
+    ///
+    /// Does nothing if the workload doesn't have a fixed timestep.
+    pub fn max_catch_up_steps(mut self, max_steps: u32) -> Self {
+        if let Some((_, prev_max_steps)) = &mut self.fixed_timestep {
+            *prev_max_steps = max_steps;
+        }
+
         self
     }
 }
@@ -567,6 +790,30 @@ fn create_workload(
         return Err(error::AddWorkload::AlreadyExists);
     }
 
+    if let Some(nested) = builder.nested_fixed_timestep {
+        return Err(error::AddWorkload::NestedFixedTimestep(nested));
+    }
+
+    for set in builder.sets.drain(..) {
+        for system in &mut builder.systems {
+            set.apply(system);
+        }
+    }
+
+    for system in &mut builder.systems {
+        system.panic_policy = system.panic_policy.or(builder.panic_policy);
+    }
+
+    // Exclusive systems are surrounded by barriers to run alone
+    for (index, system) in builder.systems.iter().enumerate() {
+        if system.exclusive {
+            builder.barriers.push(index);
+            builder.barriers.push(index + 1);
+        }
+    }
+    builder.barriers.sort_unstable();
+    builder.barriers.dedup();
+
     for index in builder.barriers.drain(..) {
         let tag = format!("__barrier__{}", index);
 
@@ -579,6 +826,13 @@ fn create_workload(
         }
     }
 
+    let exclusive_systems = builder
+        .systems
+        .iter()
+        .filter(|system| system.exclusive)
+        .map(|system| system.type_id)
+        .collect::<Vec<_>>();
+
     let mut collected_systems: Vec<(usize, WorkloadSystem)> =
         Vec::with_capacity(builder.systems.len());
 
@@ -604,6 +858,9 @@ fn create_workload(
     let batches = workloads.entry(builder.name.clone()).or_default();
 
     batches.run_if = builder.run_if;
+    batches.fixed_timestep = builder
+        .fixed_timestep
+        .map(|(step, max_steps)| FixedTimestep::new(step, max_steps));
 
     if collected_systems.len() == 1 {
         let (
@@ -613,6 +870,7 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                panic_policy,
                 ..
             },
         ) = collected_systems.pop().unwrap();
@@ -640,12 +898,17 @@ fn create_workload(
 
         batches.sequential.push(system_index);
         batches.sequential_run_if.push(run_if);
+        batches.panic_policies.push(panic_policy);
+        batches.disabled.push(AtomicBool::new(false));
+        batches.panicked.push(AtomicBool::new(false));
 
         let batch_info = BatchInfo {
             systems: (
                 Some(SystemInfo {
                     name: display_name,
+                    enabled: true,
                     type_id,
+                    exclusive: exclusive_systems.contains(&type_id),
                     borrow: borrow_constraints,
                     conflict: None,
                 }),
@@ -656,12 +919,14 @@ fn create_workload(
         return Ok(WorkloadInfo {
             name: builder.name,
             batch_info: vec![batch_info],
+            orderings: Vec::new(),
         });
     }
 
     let mut workload_info = WorkloadInfo {
         name: builder.name,
         batch_info: vec![],
+        orderings: Vec::new(),
     };
 
     // // Extract systems that have before/after requirements as they are not scheduled the same way
@@ -700,6 +965,7 @@ fn create_workload(
     let mut collected_before = Vec::new();
     let mut collected_after = Vec::new();
     let mut collected_names = Vec::new();
+    let mut collected_panic_policies = Vec::new();
 
     for (
         index,
@@ -713,6 +979,7 @@ fn create_workload(
                 require_before,
                 require_after,
                 display_name,
+                panic_policy,
                 ..
             },
         ),
@@ -725,6 +992,7 @@ fn create_workload(
         collected_before.push(core::mem::take(require_before));
         collected_after.push(core::mem::take(require_after));
         collected_names.push(display_name.clone());
+        collected_panic_policies.push(*panic_policy);
     }
 
     // Remove before/after that are not present in the workload
@@ -748,6 +1016,48 @@ fn create_workload(
         });
     }
 
+    // Record direct requirements before they are flattened
+    for (index, (_, system)) in collected_systems.iter().enumerate() {
+        let system_id = SystemId {
+            name: system.display_name.clone(),
+            type_id: system.type_id,
+        };
+
+        for (other_index, (_, other_system)) in collected_systems.iter().enumerate() {
+            if other_index == index {
+                continue;
+            }
+
+            let other_system_id = SystemId {
+                name: other_system.display_name.clone(),
+                type_id: other_system.type_id,
+            };
+            let other_tags = &collected_tags[other_index];
+
+            let mut orderings = Vec::new();
+
+            if memoize_before[&index]
+                .iter()
+                .any(|label| other_tags.contains(label))
+            {
+                orderings.push((system_id.clone(), other_system_id.clone()));
+            }
+
+            if memoize_after[&index]
+                .iter()
+                .any(|label| other_tags.contains(label))
+            {
+                orderings.push((other_system_id, system_id.clone()));
+            }
+
+            for ordering in orderings {
+                if !workload_info.orderings.contains(&ordering) {
+                    workload_info.orderings.push(ordering);
+                }
+            }
+        }
+    }
+
     let mut new_requirements = true;
     while new_requirements {
         new_requirements = false;
@@ -997,6 +1307,31 @@ fn create_workload(
         }
     }
 
+    for system_info in workload_info.batch_info.iter_mut().flat_map(|batch_info| {
+        batch_info
+            .systems
+            .0
+            .iter_mut()
+            .chain(&mut batch_info.systems.1)
+    }) {
+        system_info.exclusive = exclusive_systems.contains(&system_info.type_id);
+    }
+
+    batches.disabled = batches
+        .sequential
+        .iter()
+        .map(|_| AtomicBool::new(false))
+        .collect();
+    batches.panicked = batches
+        .sequential
+        .iter()
+        .map(|_| AtomicBool::new(false))
+        .collect();
+    batches.panic_policies = seq_system_index_map
+        .iter()
+        .map(|&index| collected_panic_policies[index])
+        .collect();
+
     Ok(workload_info)
 }
 
@@ -1077,7 +1412,9 @@ fn insert_system(
                 | (Some(other_system_info), Some(_)) => {
                     let system_info = SystemInfo {
                         name: display_name,
+                        enabled: true,
                         type_id,
+                        exclusive: false,
                         borrow: borrow_constraints,
                         conflict: Some(Conflict::Borrow {
                             type_info: Some(all_storages_type_info),
@@ -1113,7 +1450,9 @@ fn insert_system(
 
         let system_info = SystemInfo {
             name: display_name,
+            enabled: true,
             type_id,
+            exclusive: false,
             borrow: borrow_constraints,
             conflict: None,
         };
@@ -1142,7 +1481,9 @@ fn insert_system(
             {
                 let system_info = SystemInfo {
                     name: display_name,
+                    enabled: true,
                     type_id,
+                    exclusive: false,
                     borrow: borrow_constraints,
                     conflict: Some(Conflict::Borrow {
                         type_info: Some(non_send_sync_type_info.clone()),
@@ -1191,7 +1532,9 @@ fn insert_system(
 
         let system_info = SystemInfo {
             name: display_name,
+            enabled: true,
             type_id,
+            exclusive: false,
             borrow: borrow_constraints,
             conflict,
         };
@@ -1405,7 +1748,9 @@ fn insert_before_after_system(
 
     let system_info = SystemInfo {
         name: display_name.dyn_clone(),
+        enabled: true,
         type_id,
+        exclusive: false,
         borrow: borrow_constraints,
         conflict,
     };
@@ -1660,6 +2005,10 @@ mod tests {
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1690,6 +2039,10 @@ mod tests {
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1722,6 +2075,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1754,6 +2111,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1786,6 +2147,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1810,6 +2175,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1849,6 +2218,10 @@ mod tests {
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1880,6 +2253,10 @@ mod tests {
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1903,6 +2280,10 @@ mod tests {
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1927,6 +2308,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1950,6 +2335,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1989,6 +2378,10 @@ mod tests {
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2013,6 +2406,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2036,6 +2433,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2059,6 +2460,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2083,6 +2488,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2115,6 +2524,10 @@ mod tests {
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2140,6 +2553,10 @@ mod tests {
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2181,6 +2598,10 @@ mod tests {
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2477,6 +2898,10 @@ mod tests {
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
                 run_if: None,
+                fixed_timestep: None,
+                panic_policies: Vec::new(),
+                disabled: Vec::new(),
+                panicked: Vec::new(),
             }
         );
     }
@@ -40,19 +40,14 @@ impl BulkInsert for () {
     where
         Self: Sized,
     {
-        let iter = iter.into_iter();
-        let len = iter.size_hint().0;
+        let len = iter.into_iter().count();
 
         let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
-        let entities_len = entities.data.len();
 
-        entities.bulk_generate(len);
-        for _ in iter.skip(len) {
-            entities.generate();
-        }
+        let new_entities = entities.bulk_generate(len);
         BulkEntityIter {
-            iter: entities.data[entities_len..].iter().copied(),
-            slice: &entities.data[entities_len..],
+            iter: entities.data[new_entities.clone()].iter().copied(),
+            slice: &entities.data[new_entities],
         }
     }
 }
@@ -73,13 +68,14 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         sparse_set.data.extend(iter);
 
         // generate new EntityId for the entities created
-        let entities_len = entities.data.len();
         let old_len = sparse_set.dense.len();
         let new_entities_count = sparse_set.data.len() - old_len;
-        let new_entities = entities.bulk_generate(new_entities_count);
+        let new_entities_range = entities.bulk_generate(new_entities_count);
+        let new_entities = &entities.data[new_entities_range.clone()];
 
         // add new EntityId to the storage for the components we added above
         sparse_set.dense.extend_from_slice(new_entities);
+        sparse_set.version += 1;
 
         // add tracking info if needed
         if sparse_set.is_tracking_insertion() {
@@ -103,13 +99,15 @@ impl<T: Send + Sync + Component> BulkInsert for T {
             }
         }
 
+        sparse_set.notify_bulk_insert(old_len);
+
         drop((entities, sparse_set));
 
         let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
 
         BulkEntityIter {
-            iter: entities.data[entities_len..].iter().copied(),
-            slice: &entities.data[entities_len..],
+            iter: entities.data[new_entities_range.clone()].iter().copied(),
+            slice: &entities.data[new_entities_range],
         }
     }
 }
@@ -148,13 +146,15 @@ macro_rules! impl_bulk_insert {
                     )*
                 }
 
-                let entities_len = entities.data.len();
                 let new_entities_count = $sparse_set1.data.len() - $sparse_set1.dense.len();
-                let new_entities = entities.bulk_generate(new_entities_count);
+                let new_entities_range = entities.bulk_generate(new_entities_count);
+                let new_entities = &entities.data[new_entities_range.clone()];
 
                 $sparse_set1.dense.extend_from_slice(new_entities);
+                $sparse_set1.version += 1;
                 $(
                     $sparse_set.dense.extend_from_slice(new_entities);
+                    $sparse_set.version += 1;
                 )*
 
                 if $sparse_set1.is_tracking_insertion() {
@@ -193,13 +193,20 @@ macro_rules! impl_bulk_insert {
                     }
                 )*
 
+                let old_len = $sparse_set1.dense.len() - new_entities_count;
+                $sparse_set1.notify_bulk_insert(old_len);
+                $(
+                    let old_len = $sparse_set.dense.len() - new_entities_count;
+                    $sparse_set.notify_bulk_insert(old_len);
+                )*
+
                 drop((entities, $sparse_set1, $($sparse_set),*));
 
                 let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
 
                 BulkEntityIter {
-                    iter: entities.data[entities_len..].iter().copied(),
-                    slice: &entities.data[entities_len..],
+                    iter: entities.data[new_entities_range.clone()].iter().copied(),
+                    slice: &entities.data[new_entities_range],
                 }
             }
         }
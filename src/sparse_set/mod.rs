@@ -17,13 +17,15 @@ pub(crate) use window::{FullRawWindow, FullRawWindowMut};
 
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::listener::{ListenerId, Listeners};
 use crate::memory_usage::StorageMemoryUsage;
 use crate::storage::{Storage, StorageId};
 use crate::tracking::Tracking;
 use crate::tracking::{is_track_within_bounds, TrackingTimestamp};
 use crate::{error, track};
+use alloc::boxed::Box;
 use alloc::vec::Vec;
-use core::any::type_name;
+use core::any::{type_name, Any};
 use core::{
     cmp::{Ord, Ordering},
     fmt,
@@ -54,8 +56,15 @@ pub struct SparseSet<T: Component> {
     pub(crate) is_tracking_modification: bool,
     pub(crate) is_tracking_deletion: bool,
     pub(crate) is_tracking_removal: bool,
+    /// Incremented each time an entity is added to or removed from the storage.
+    pub(crate) version: u64,
+    on_insert: Listeners<ComponentListener<T>>,
+    on_remove: Listeners<ComponentListener<T>>,
+    next_listener_id: u64,
 }
 
+type ComponentListener<T> = dyn FnMut(EntityId, &T) + Send + Sync;
+
 impl<T: fmt::Debug + Component> fmt::Debug for SparseSet<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list()
@@ -81,6 +90,10 @@ impl<T: Component> SparseSet<T> {
             is_tracking_modification: false,
             is_tracking_deletion: false,
             is_tracking_removal: false,
+            version: 0,
+            on_insert: Listeners::new(),
+            on_remove: Listeners::new(),
+            next_listener_id: 0,
         }
     }
     /// Returns a new [`SparseSet`] to be used in custom storage.
@@ -95,6 +108,69 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: Component> SparseSet<T> {
+    /// Registers a callback called each time a component is added to this storage, after the insertion.\
+    /// Replacing a component also counts as an insertion.\
+    /// Returns an id that can be used to unregister the callback with [`SparseSet::remove_listener`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     u32s.on_insert(|entity, component| println!("{:?} inserted {}", entity, component.0));
+    /// });
+    ///
+    /// world.add_entity((U32(0),));
+    /// ```
+    pub fn on_insert(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) -> ListenerId {
+        self.on_insert.push(&mut self.next_listener_id, Box::new(f))
+    }
+    /// Registers a callback called each time a component is removed or deleted from this storage.\
+    /// This includes deleting or stripping its entity and clearing the storage.\
+    /// Returns an id that can be used to unregister the callback with [`SparseSet::remove_listener`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     u32s.on_remove(|entity, component| println!("{:?} lost {}", entity, component.0));
+    /// });
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    /// world.delete_entity(entity);
+    /// ```
+    pub fn on_remove(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) -> ListenerId {
+        self.on_remove.push(&mut self.next_listener_id, Box::new(f))
+    }
+    /// Unregisters an insertion or removal callback.\
+    /// Returns `true` if the callback was registered.
+    pub fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.on_insert.remove(id) || self.on_remove.remove(id)
+    }
+    /// Calls the insertion callbacks for all components added after `old_len` without going through `insert`.
+    pub(crate) fn notify_bulk_insert(&mut self, old_len: usize) {
+        for listener in self.on_insert.iter_mut() {
+            for (&entity, component) in self.dense[old_len..].iter().zip(&self.data[old_len..]) {
+                (listener)(entity, component);
+            }
+        }
+    }
+}
+
 impl<T: Component> SparseSet<T> {
     /// Returns `true` if `entity` owns a component in this storage.
     #[inline]
@@ -178,6 +254,7 @@ impl<T: Component> SparseSet<T> {
 
             self.dense.push(entity);
             self.data.push(value);
+            self.version += 1;
 
             old_component = None;
         } else if entity.gen() >= sparse_entity.gen() {
@@ -188,6 +265,8 @@ impl<T: Component> SparseSet<T> {
             if entity.gen() == sparse_entity.gen() {
                 old_component = Some(old_data);
             } else {
+                // the component now belongs to a different entity
+                self.version += 1;
                 old_component = None;
             }
 
@@ -205,7 +284,17 @@ impl<T: Component> SparseSet<T> {
 
             dense_entity.copy_index_gen(entity);
         } else {
-            old_component = None;
+            return None;
+        }
+
+        if !self.on_insert.is_empty() {
+            // at this point entity is always present in the storage
+            let index = unsafe { self.sparse.get(entity).unwrap_unchecked().uindex() };
+            let component = unsafe { self.data.get_unchecked(index) };
+
+            for listener in self.on_insert.iter_mut() {
+                (listener)(entity, component);
+            }
         }
 
         old_component
@@ -249,6 +338,7 @@ impl<T: Component> SparseSet<T> {
             }
 
             self.dense.swap_remove(sparse_entity.uindex());
+            self.version += 1;
             if self.is_tracking_insertion() {
                 self.insertion_data.swap_remove(sparse_entity.uindex());
             }
@@ -257,6 +347,10 @@ impl<T: Component> SparseSet<T> {
             }
             let component = self.data.swap_remove(sparse_entity.uindex());
 
+            for listener in self.on_remove.iter_mut() {
+                (listener)(entity, &component);
+            }
+
             // The SparseSet could now be empty or the removed component could have been the last one
             if sparse_entity.uindex() < self.dense.len() {
                 unsafe {
@@ -547,6 +641,8 @@ impl<T: Component> SparseSet<T> {
 
     /// Deletes all components in this storage.
     pub(crate) fn private_clear(&mut self, current: u32) {
+        self.version += 1;
+
         for &id in &self.dense {
             unsafe {
                 *self.sparse.get_mut_unchecked(id) = EntityId::dead();
@@ -555,6 +651,12 @@ impl<T: Component> SparseSet<T> {
 
         self.insertion_data.clear();
 
+        for listener in self.on_remove.iter_mut() {
+            for (&entity, component) in self.dense.iter().zip(&self.data) {
+                (listener)(entity, component);
+            }
+        }
+
         let is_tracking_deletion = self.is_tracking_deletion();
 
         let iter = self
@@ -570,6 +672,14 @@ impl<T: Component> SparseSet<T> {
 
     /// Creates a draining iterator that empties the storage and yields the removed items.
     pub(crate) fn private_drain(&mut self, current: u32) -> SparseSetDrain<'_, T> {
+        self.version += 1;
+
+        for listener in self.on_remove.iter_mut() {
+            for (&entity, component) in self.dense.iter().zip(&self.data) {
+                (listener)(entity, component);
+            }
+        }
+
         if self.is_tracking_removal {
             self.removal_data
                 .extend(self.dense.iter().map(|&entity| (entity, current)));
@@ -636,6 +746,18 @@ impl<T: 'static + Component> Storage for SparseSet<T> {
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
         Some(&self.sparse)
     }
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
+    fn any_at(&self, index: usize) -> Option<&dyn Any> {
+        self.data
+            .get(index)
+            .map(|component| -> &dyn Any { component })
+    }
+    #[inline]
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self, entity)
+    }
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
@@ -18,6 +18,8 @@ pub struct FullRawWindow<'a, T> {
     pub(crate) last_insertion: u32,
     pub(crate) last_modification: u32,
     pub(crate) current: u32,
+    pub(crate) is_tracking_insertion: bool,
+    pub(crate) is_tracking_modification: bool,
     _phantom: PhantomData<&'a T>,
 }
 
@@ -41,6 +43,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             last_insertion: view.last_insertion,
             last_modification: view.last_modification,
             current: view.current,
+            is_tracking_insertion: view.is_tracking_insertion(),
+            is_tracking_modification: view.is_tracking_modification(),
             _phantom: PhantomData,
         }
     }
@@ -74,6 +78,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
                 last_insertion,
                 last_modification,
                 current,
+                is_tracking_insertion: sparse_set.is_tracking_insertion(),
+                is_tracking_modification: sparse_set.is_tracking_modification(),
                 _phantom: PhantomData,
             },
             all_borrow,
@@ -97,6 +103,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             last_insertion: view.last_insertion,
             last_modification: view.last_modification,
             current: view.current,
+            is_tracking_insertion: view.is_tracking_insertion(),
+            is_tracking_modification: view.is_tracking_modification(),
             _phantom: PhantomData,
         }
     }
@@ -154,6 +162,8 @@ impl<T: Component> Clone for FullRawWindow<'_, T> {
             last_insertion: self.last_insertion,
             last_modification: self.last_modification,
             current: self.current,
+            is_tracking_insertion: self.is_tracking_insertion,
+            is_tracking_modification: self.is_tracking_modification,
             _phantom: PhantomData,
         }
     }
@@ -170,6 +180,7 @@ pub struct FullRawWindowMut<'a, T> {
     pub(crate) last_insertion: u32,
     pub(crate) last_modification: u32,
     pub(crate) current: u32,
+    pub(crate) is_tracking_insertion: bool,
     pub(crate) is_tracking_modification: bool,
     _phantom: PhantomData<&'a mut T>,
 }
@@ -194,6 +205,7 @@ impl<'w, T: Component> FullRawWindowMut<'w, T> {
             last_insertion: view.last_insertion,
             last_modification: view.last_modification,
             current: view.current,
+            is_tracking_insertion: view.is_tracking_insertion(),
             is_tracking_modification: view.is_tracking_modification(),
             _phantom: PhantomData,
         }
@@ -229,6 +241,7 @@ impl<'w, T: Component> FullRawWindowMut<'w, T> {
                 last_insertion,
                 last_modification,
                 current,
+                is_tracking_insertion: sparse_set.is_tracking_insertion(),
                 is_tracking_modification: sparse_set.is_tracking_modification(),
                 _phantom: PhantomData,
             },
@@ -290,6 +303,7 @@ impl<T: Component> Clone for FullRawWindowMut<'_, T> {
             last_insertion: self.last_insertion,
             last_modification: self.last_modification,
             current: self.current,
+            is_tracking_insertion: self.is_tracking_insertion,
             is_tracking_modification: self.is_tracking_modification,
             _phantom: PhantomData,
         }
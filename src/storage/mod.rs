@@ -59,6 +59,30 @@ pub trait Storage: SizedAny {
     fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
         None
     }
+    /// Returns the entities of a [`SparseSet`], in the same order as their components.
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    fn dense_entities(&self) -> Option<&[EntityId]> {
+        None
+    }
+    /// Returns the component at `index` in a [`SparseSet`]'s dense array as `&dyn Any`.
+    ///
+    /// [`SparseSet`]: crate::sparse_set::SparseSet
+    fn any_at(&self, _index: usize) -> Option<&dyn Any> {
+        None
+    }
+    /// Returns `true` if the storage has a component for `entity`.
+    ///
+    /// By default looks `entity` up in [`sparse_array`](Storage::sparse_array).\
+    /// Storages not tied to entities, like unique storages, return `false`.
+    #[inline]
+    fn contains(&self, entity: EntityId) -> bool {
+        self.sparse_array().is_some_and(|sparse_array| {
+            sparse_array
+                .get(entity)
+                .is_some_and(|sparse_entity| sparse_entity.gen() == entity.gen())
+        })
+    }
     /// Returns `true` if the storage is empty.
     fn is_empty(&self) -> bool {
         false
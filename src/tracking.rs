@@ -1,7 +1,9 @@
 mod iterator_wrapper;
 mod tuple_track;
 
-pub use iterator_wrapper::{Inserted, InsertedOrModified, Modified};
+pub use iterator_wrapper::{
+    Changed, ChangedIndex, Deleted, Inserted, InsertedOrModified, Modified, Removed,
+};
 pub use tuple_track::TupleTrack;
 
 use crate::component::Component;
@@ -1,4 +1,9 @@
+use crate::entity_id::EntityId;
 use crate::not::Not;
+use crate::tracking::TrackingTimestamp;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 /// Wrapper type allowing iterating over *inserted* flagged components.
 #[derive(Clone)]
@@ -35,3 +40,195 @@ impl<Storage> core::ops::Not for InsertedOrModified<Storage> {
         Not(self)
     }
 }
+
+/// Filter yielding the entities with all components in `views` and at least one of them *inserted* or *modified*.
+///
+/// Storages not tracking insertion nor modification never have changed components.\
+/// The components of all views are yielded, like a regular tuple iteration.
+///
+/// ### Example
+/// ```
+/// use shipyard::{track, Changed, Component, IntoIter, View, ViewMut, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// #[derive(Component)]
+/// struct Rotation(f32);
+///
+/// #[derive(Component)]
+/// struct Sprite;
+///
+/// let mut world = World::new();
+/// world.track_modification::<(Position, Rotation)>();
+///
+/// let e0 = world.add_entity((Position(0.0), Rotation(0.0), Sprite));
+/// let e1 = world.add_entity((Position(0.0), Rotation(0.0), Sprite));
+/// world.add_entity((Position(0.0), Rotation(0.0), Sprite));
+/// world.add_entity((Position(0.0), Rotation(0.0)));
+///
+/// let timestamp = world.get_tracking_timestamp();
+///
+/// let (mut positions, mut rotations, sprites) = world
+///     .borrow::<(
+///         ViewMut<Position, track::Modification>,
+///         ViewMut<Rotation, track::Modification>,
+///         View<Sprite>,
+///     )>()
+///     .unwrap();
+///
+/// positions[e0].0 = 1.0;
+/// rotations[e1].0 = 1.0;
+///
+/// let changed = (Changed::new((&positions, &rotations)).since(timestamp), &sprites)
+///     .iter()
+///     .count();
+///
+/// assert_eq!(changed, 2);
+/// ```
+#[derive(Clone)]
+pub struct Changed<T> {
+    pub(crate) views: T,
+    pub(crate) since: Option<u32>,
+}
+
+impl<T> Changed<T> {
+    /// Creates a filter yielding the entities with at least one component in `views` *inserted* or *modified*
+    /// since the last run of the system, or the last clear outside of workloads.
+    pub fn new(views: T) -> Changed<T> {
+        Changed { views, since: None }
+    }
+    /// Only considers the insertions and modifications that happened after `timestamp` was taken.\
+    /// See [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp).
+    pub fn since(mut self, timestamp: TrackingTimestamp) -> Changed<T> {
+        self.since = Some(timestamp.0);
+
+        self
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct ChangedIndex<T>(pub(crate) T);
+
+impl<T> From<usize> for ChangedIndex<T> {
+    fn from(_: usize) -> Self {
+        unreachable!()
+    }
+}
+
+/// Iteration source over the entities whose component was *removed*.\
+/// Created with [`View::as_removed`] or [`ViewMut::as_removed`].
+///
+/// Yields `()` for each entity, it can be joined with other views to get their components.
+///
+/// ### Example
+/// ```
+/// use shipyard::{track, Component, IntoIter, IntoWithId, View, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// #[derive(Component)]
+/// struct Sprite;
+///
+/// let mut world = World::new();
+/// world.track_removal::<Position>();
+///
+/// let e0 = world.add_entity((Position(0.0), Sprite));
+/// let e1 = world.add_entity((Position(0.0),));
+///
+/// world.remove::<Position>(e0);
+/// world.remove::<Position>(e1);
+///
+/// let (positions, sprites) = world
+///     .borrow::<(View<Position, track::Removal>, View<Sprite>)>()
+///     .unwrap();
+///
+/// let ids = (positions.as_removed(), &sprites)
+///     .iter()
+///     .ids()
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(ids, [e0]);
+/// ```
+///
+/// [`View::as_removed`]: crate::View::as_removed
+/// [`ViewMut::as_removed`]: crate::ViewMut::as_removed
+pub struct Removed<'a, T> {
+    /// Sorted and deduplicated.
+    pub(crate) entities: Arc<[EntityId]>,
+    pub(crate) _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T> Removed<'a, T> {
+    pub(crate) fn new(entities: impl Iterator<Item = EntityId>) -> Removed<'a, T> {
+        let mut entities = entities.collect::<Vec<_>>();
+        entities.sort_unstable();
+        entities.dedup();
+
+        Removed {
+            entities: entities.into(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Removed<'_, T> {
+    fn clone(&self) -> Self {
+        Removed {
+            entities: self.entities.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Iteration source over the *deleted* components.\
+/// Created with [`View::as_deleted`] or [`ViewMut::as_deleted`].
+///
+/// Yields a reference to the deleted component, it can be joined with other views to get their components.
+///
+/// [`View::as_deleted`]: crate::View::as_deleted
+/// [`ViewMut::as_deleted`]: crate::ViewMut::as_deleted
+pub struct Deleted<'a, T> {
+    /// Sorted and deduplicated.
+    pub(crate) entities: Arc<[EntityId]>,
+    /// Index of each entity in `deletion_data`.
+    pub(crate) indices: Arc<[usize]>,
+    pub(crate) deletion_data: &'a [(EntityId, u32, T)],
+}
+
+impl<'a, T> Deleted<'a, T> {
+    pub(crate) fn new(
+        deletion_data: &'a [(EntityId, u32, T)],
+        mut is_within_bounds: impl FnMut(u32) -> bool,
+    ) -> Deleted<'a, T> {
+        let mut deleted = deletion_data
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, timestamp, _))| is_within_bounds(*timestamp))
+            .map(|(index, (entity, _, _))| (*entity, index))
+            .collect::<Vec<_>>();
+        // A component can be deleted multiple times, only its latest deletion is kept
+        deleted.sort_unstable_by(|(entity, index), (other_entity, other_index)| {
+            entity.cmp(other_entity).then(other_index.cmp(index))
+        });
+        deleted.dedup_by_key(|(entity, _)| *entity);
+
+        Deleted {
+            entities: deleted.iter().map(|(entity, _)| *entity).collect(),
+            indices: deleted.iter().map(|(_, index)| *index).collect(),
+            deletion_data,
+        }
+    }
+}
+
+impl<T> Clone for Deleted<'_, T> {
+    fn clone(&self) -> Self {
+        Deleted {
+            entities: self.entities.clone(),
+            indices: self.indices.clone(),
+            deletion_data: self.deletion_data,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 mod all_storages;
 mod entities;
+mod event_reader;
+mod event_writer;
+mod local;
 mod unique_view;
 mod unique_view_mut;
 mod view;
@@ -7,6 +10,10 @@ mod view_mut;
 
 pub use all_storages::{AllStoragesView, AllStoragesViewMut};
 pub use entities::{EntitiesView, EntitiesViewMut};
+pub(crate) use event_reader::EventCursor;
+pub use event_reader::EventReader;
+pub use event_writer::EventWriter;
+pub use local::{Local, SystemLocals, SystemLocalsCursor};
 pub use unique_view::UniqueView;
 pub use unique_view_mut::UniqueViewMut;
 pub use view::View;
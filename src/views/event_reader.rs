@@ -0,0 +1,94 @@
+use crate::atomic_refcell::SharedBorrow;
+use crate::event::Events;
+use crate::views::Local;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Shared view over an event storage, used to read events.
+///
+/// Each system keeps its own cursor, it only sees the events it didn't read yet.\
+/// Outside of workloads, for example with [`World::run`], all events that didn't expire are returned.
+///
+/// ### Example
+/// ```
+/// use shipyard::{EventReader, EventWriter, World};
+///
+/// struct Collision(u32);
+///
+/// let world = World::new();
+///
+/// world.run(|mut collisions: EventWriter<Collision>| {
+///     collisions.send(Collision(0));
+///     collisions.send(Collision(1));
+/// });
+///
+/// world.run(|mut collisions: EventReader<Collision>| {
+///     assert_eq!(collisions.len(), 2);
+///
+///     let ids: Vec<u32> = collisions.iter().map(|collision| collision.0).collect();
+///     assert_eq!(ids, [0, 1]);
+///
+///     assert!(collisions.is_empty());
+/// });
+/// ```
+///
+/// [`World::run`]: crate::World::run
+pub struct EventReader<'a, E: Send + Sync + 'static> {
+    pub(crate) events: &'a Events<E>,
+    pub(crate) cursor: Local<'a, EventCursor<E>>,
+    pub(crate) run: u32,
+    pub(crate) _borrow: SharedBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+/// Id of the next event a system will read.
+pub(crate) struct EventCursor<E> {
+    next: usize,
+    _phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> Default for EventCursor<E> {
+    fn default() -> Self {
+        EventCursor {
+            next: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, E: Send + Sync + 'static> EventReader<'a, E> {
+    /// Returns the unread events, oldest first, and marks them as read.
+    #[inline]
+    pub fn iter(&mut self) -> impl Iterator<Item = &'a E> {
+        let (older, newer) = self.events.events_from(self.cursor.next, self.run);
+
+        self.cursor.next = self.events.event_count();
+
+        older.iter().chain(newer)
+    }
+    /// Returns the number of unread events.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let (older, newer) = self.events.events_from(self.cursor.next, self.run);
+
+        older.len() + newer.len()
+    }
+    /// Returns `true` if there is no unread event.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Marks all events as read.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cursor.next = self.events.event_count();
+    }
+}
+
+impl<E: Send + Sync + 'static> fmt::Debug for EventReader<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventReader")
+            .field("unread", &self.len())
+            .finish()
+    }
+}
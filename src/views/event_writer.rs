@@ -0,0 +1,71 @@
+use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
+use crate::event::Events;
+use core::fmt;
+
+/// Exclusive view over an event storage, used to send events.
+///
+/// Events can be read by [`EventReader`] until the end of the workload run following the one they were sent in.\
+/// Outside of workloads, [`World::update_events`] can be used to expire events manually.
+///
+/// ### Example
+/// ```
+/// use shipyard::{EventReader, EventWriter, Workload, World};
+///
+/// struct Collision(u32);
+///
+/// fn send(mut collisions: EventWriter<Collision>) {
+///     collisions.send(Collision(0));
+/// }
+///
+/// fn read(mut collisions: EventReader<Collision>) {
+///     assert_eq!(collisions.iter().count(), 1);
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new("Collisions")
+///     .with_system(send)
+///     .with_system(read)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Collisions").unwrap();
+/// ```
+///
+/// [`EventReader`]: crate::EventReader
+/// [`World::update_events`]: crate::World::update_events
+pub struct EventWriter<'a, E: Send + Sync + 'static> {
+    pub(crate) events: &'a mut Events<E>,
+    pub(crate) _borrow: ExclusiveBorrow<'a>,
+    pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<E: Send + Sync + 'static> EventWriter<'_, E> {
+    /// Sends an event.
+    #[inline]
+    pub fn send(&mut self, event: E) {
+        self.events.send(event);
+    }
+    /// Sends all events yielded by `events`.
+    #[inline]
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) {
+        for event in events {
+            self.events.send(event);
+        }
+    }
+}
+
+impl<E: Send + Sync + 'static> Extend<E> for EventWriter<'_, E> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.send_batch(iter);
+    }
+}
+
+impl<E: Send + Sync + 'static> fmt::Debug for EventWriter<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventWriter")
+            .field("event_count", &self.events.event_count())
+            .finish()
+    }
+}
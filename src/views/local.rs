@@ -0,0 +1,194 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::{ARefMut, AtomicRefCell, ExclusiveBorrow, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::error;
+use crate::scheduler::TypeInfo;
+use crate::storage::StorageId;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// State owned by a system and kept between its runs.
+///
+/// Each system has its own `T`, created with `T::default()` when the system is added to a workload.\
+/// `Local` doesn't borrow anything from the `World` and never prevents systems from running in parallel.
+///
+/// Outside of workloads, for example with [`World::run`], a new `T::default()` is created each time.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Local, Workload, World};
+///
+/// fn count_runs(mut runs: Local<u32>) {
+///     *runs += 1;
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new("Count")
+///     .with_system(count_runs)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Count").unwrap();
+/// world.run_workload("Count").unwrap();
+/// ```
+///
+/// [`World::run`]: crate::World::run
+pub struct Local<'a, T: Default + Send + Sync + 'static> {
+    local: LocalInner<'a, T>,
+}
+
+enum LocalInner<'a, T> {
+    System {
+        local: &'a mut T,
+        _borrow: ExclusiveBorrow<'a>,
+    },
+    Owned(T),
+}
+
+impl<T: Default + Send + Sync + 'static> Deref for Local<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match &self.local {
+            LocalInner::System { local, .. } => local,
+            LocalInner::Owned(local) => local,
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> DerefMut for Local<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.local {
+            LocalInner::System { local, .. } => local,
+            LocalInner::Owned(local) => local,
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> AsRef<T> for Local<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> AsMut<T> for Local<'_, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: fmt::Debug + Default + Send + Sync + 'static> fmt::Debug for Local<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Borrow for Local<'_, T> {
+    type View<'a> = Local<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        _all_storages: &'a AllStorages,
+        _all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<u32>,
+        _current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(Local {
+            local: LocalInner::Owned(T::default()),
+        })
+    }
+    #[inline]
+    fn init_locals(locals: &mut SystemLocals) {
+        locals.push::<T>();
+    }
+    #[inline]
+    fn borrow_with_locals<'a>(
+        _all_storages: &'a AllStorages,
+        _all_borrow: Option<SharedBorrow<'a>>,
+        locals: &mut SystemLocalsCursor<'a>,
+        _last_run: Option<u32>,
+        _current: u32,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let local = match locals.next() {
+            Some(local) => local,
+            None => {
+                return Ok(Local {
+                    local: LocalInner::Owned(T::default()),
+                })
+            }
+        };
+
+        let local = local
+            .borrow_mut()
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<Local<'static, T>>()),
+                id: StorageId::of::<Local<'static, T>>(),
+                borrow,
+            })?;
+        let local = ARefMut::map(local, |local| local.downcast_mut::<T>().unwrap());
+        let (local, borrow) = unsafe { ARefMut::destructure(local) };
+
+        Ok(Local {
+            local: LocalInner::System {
+                local,
+                _borrow: borrow,
+            },
+        })
+    }
+}
+
+// SAFE: Local doesn't borrow anything from the World.
+unsafe impl<T: Default + Send + Sync + 'static> BorrowInfo for Local<'_, T> {
+    fn borrow_info(_info: &mut Vec<TypeInfo>) {}
+    fn enable_tracking(
+        _enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+    }
+}
+
+/// [`Local`] states of a system.
+///
+/// Each [`Local`] view gets its own state, in the order the system's views are borrowed.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct SystemLocals {
+    locals: Vec<AtomicRefCell<Box<dyn Any + Send + Sync>>>,
+}
+
+// SAFE: all states are `Send + Sync` and stored using `AtomicRefCell::new`.
+#[cfg(feature = "thread_local")]
+unsafe impl Send for SystemLocals {}
+
+impl SystemLocals {
+    pub(crate) fn new() -> SystemLocals {
+        SystemLocals::default()
+    }
+    fn push<T: Default + Send + Sync + 'static>(&mut self) {
+        self.locals.push(AtomicRefCell::new(Box::new(T::default())));
+    }
+    pub(crate) fn cursor(&self) -> SystemLocalsCursor<'_> {
+        SystemLocalsCursor {
+            locals: self.locals.iter(),
+        }
+    }
+}
+
+/// Hands out a system's [`Local`] states one view at a time.
+#[doc(hidden)]
+pub struct SystemLocalsCursor<'a> {
+    locals: core::slice::Iter<'a, AtomicRefCell<Box<dyn Any + Send + Sync>>>,
+}
+
+impl<'a> SystemLocalsCursor<'a> {
+    fn next(&mut self) -> Option<&'a AtomicRefCell<Box<dyn Any + Send + Sync>>> {
+        self.locals.next()
+    }
+}
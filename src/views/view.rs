@@ -4,12 +4,15 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
+use crate::iter::Chunks;
+use crate::optional::Optional;
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
-    is_track_within_bounds, DeletionTracking, Inserted, InsertedOrModified, InsertionTracking,
-    ModificationTracking, Modified, RemovalOrDeletionTracking, RemovalTracking, Track, Tracking,
+    is_track_within_bounds, Deleted, DeletionTracking, Inserted, InsertedOrModified,
+    InsertionTracking, ModificationTracking, Modified, RemovalOrDeletionTracking, RemovalTracking,
+    Removed, Track, Tracking,
 };
 use core::fmt;
 use core::marker::PhantomData;
@@ -18,6 +21,8 @@ use core::ops::Deref;
 /// Shared view over a component storage.
 pub struct View<'a, T: Component, TRACK = track::Untracked> {
     pub(crate) sparse_set: &'a SparseSet<T>,
+    /// Used to find entities' [`Name`](crate::Name).
+    pub(crate) all_storages: &'a AllStorages,
     pub(crate) all_borrow: Option<SharedBorrow<'a>>,
     pub(crate) borrow: SharedBorrow<'a>,
     pub(crate) last_insertion: u32,
@@ -62,6 +67,7 @@ impl<'a, T: Component> View<'a, T, track::Untracked> {
         if let Some(sparse_set) = storage.as_any().downcast_ref() {
             Ok(View {
                 sparse_set,
+                all_storages,
                 all_borrow: Some(all_borrow),
                 borrow,
                 last_insertion: 0,
@@ -76,6 +82,53 @@ impl<'a, T: Component> View<'a, T, track::Untracked> {
     }
 }
 
+impl<TRACK, T: Component> View<'_, T, TRACK> {
+    /// Wraps this view to iterate components that might not be present.\
+    /// See [`Optional`].
+    #[inline]
+    pub fn optional(&self) -> Optional<&Self> {
+        Optional(self)
+    }
+    /// Iterates the entities and components of this storage in slices of `chunk_size` elements.\
+    /// The last chunk can be shorter.\
+    /// Tuples of views are chunked with [`IntoChunks::iter_chunks`](crate::IntoChunks::iter_chunks).
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Mass(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for i in 0..10 {
+    ///     world.add_entity((Mass(i as f32),));
+    /// }
+    ///
+    /// let masses = world.borrow::<View<Mass>>().unwrap();
+    ///
+    /// let total: f32 = masses
+    ///     .iter_chunks(4)
+    ///     .map(|(_, masses)| masses.iter().map(|mass| mass.0).sum::<f32>())
+    ///     .sum();
+    ///
+    /// assert_eq!(total, 45.0);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn iter_chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        Chunks {
+            entities: self.sparse_set.dense.chunks(chunk_size),
+            data: self.sparse_set.data.chunks(chunk_size),
+        }
+    }
+}
+
 impl<TRACK, T: Component> View<'_, T, TRACK>
 where
     Track<TRACK>: InsertionTracking,
@@ -162,6 +215,14 @@ where
             })
     }
 
+    /// Returns an iteration source over the *deleted* components, it can be joined with other views.\
+    /// See [`Deleted`].
+    pub fn as_deleted(&self) -> Deleted<'_, T> {
+        Deleted::new(&self.sparse_set.deletion_data, |timestamp| {
+            is_track_within_bounds(timestamp, self.last_removal_or_deletion, self.current)
+        })
+    }
+
     /// Inside a workload returns `true` if `entity`'s component was deleted since the last run of this system.\
     /// Outside workloads returns `true` if `entity`'s component was deleted since the last call to [`clear_all_deleted`](SparseSet::clear_all_deleted).\
     /// Returns `false` if `entity` does not have a component in this storage.
@@ -189,6 +250,12 @@ where
             })
     }
 
+    /// Returns an iteration source over the entities whose component was *removed*, it can be joined with other views.\
+    /// See [`Removed`].
+    pub fn as_removed(&self) -> Removed<'_, T> {
+        Removed::new(self.removed())
+    }
+
     /// Inside a workload returns `true` if `entity`'s component was removed since the last run of this system.\
     /// Outside workloads returns `true` if `entity`'s component was removed since the last call to [`clear_all_removed`](SparseSet::clear_all_removed).\
     /// Returns `false` if `entity` does not have a component in this storage.
@@ -246,6 +313,7 @@ impl<'a, T: Component, TRACK> Clone for View<'a, T, TRACK> {
     fn clone(&self) -> Self {
         View {
             sparse_set: self.sparse_set,
+            all_storages: self.all_storages,
             borrow: self.borrow.clone(),
             all_borrow: self.all_borrow.clone(),
             last_insertion: self.last_insertion,
@@ -259,7 +327,10 @@ impl<'a, T: Component, TRACK> Clone for View<'a, T, TRACK> {
 
 impl<T: fmt::Debug + Component, TRACK> fmt::Debug for View<'_, T, TRACK> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.sparse_set.fmt(f)
+        self.all_storages.with_names(|names| match names {
+            Some(names) => self.sparse_set.debug_with_names(names).fmt(f),
+            None => self.sparse_set.fmt(f),
+        })
     }
 }
 
@@ -268,6 +339,22 @@ impl<T: Component, TRACK> core::ops::Index<EntityId> for View<'_, T, TRACK> {
     #[track_caller]
     #[inline]
     fn index(&self, entity: EntityId) -> &Self::Output {
-        self.get(entity).unwrap()
+        match self.get(entity) {
+            Ok(component) => component,
+            Err(err) => missing_component_panic(self.all_storages, err),
+        }
     }
 }
+
+/// Panics with `err`, using the entity's [`Name`](crate::Name) when possible.
+#[cold]
+#[track_caller]
+pub(crate) fn missing_component_panic(
+    all_storages: &AllStorages,
+    err: error::MissingComponent,
+) -> ! {
+    all_storages.with_names(|names| match names {
+        Some(names) => panic!("{:?}", err.with_names(names)),
+        None => panic!("{:?}", err),
+    })
+}
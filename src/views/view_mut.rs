@@ -4,13 +4,18 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
-use crate::sparse_set::{SparseSet, SparseSetDrain};
+use crate::iter::{Chunks, ChunksMut, Combinations, IntoCombinations, IntoIter};
+use crate::optional::Optional;
+use crate::r#mut::Mut;
+use crate::sparse_set::{FullRawWindowMut, SparseSet, SparseSetDrain};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
-    is_track_within_bounds, DeletionTracking, Inserted, InsertedOrModified, InsertionTracking,
-    ModificationTracking, Modified, RemovalOrDeletionTracking, RemovalTracking, Track, Tracking,
+    is_track_within_bounds, Deleted, DeletionTracking, Inserted, InsertedOrModified,
+    InsertionTracking, ModificationTracking, Modified, RemovalOrDeletionTracking, RemovalTracking,
+    Removed, Track, Tracking,
 };
+use crate::views::view::missing_component_panic;
 use core::fmt;
 use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
@@ -18,6 +23,8 @@ use core::ops::{Deref, DerefMut};
 /// Exclusive view over a component storage.
 pub struct ViewMut<'a, T: Component, TRACK = track::Untracked> {
     pub(crate) sparse_set: &'a mut SparseSet<T>,
+    /// Used to find entities' [`Name`](crate::Name).
+    pub(crate) all_storages: &'a AllStorages,
     pub(crate) _all_borrow: Option<SharedBorrow<'a>>,
     pub(crate) _borrow: ExclusiveBorrow<'a>,
     pub(crate) last_insertion: u32,
@@ -64,6 +71,7 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
         if let Some(sparse_set) = storage.any_mut().downcast_mut() {
             Ok(ViewMut {
                 sparse_set,
+                all_storages,
                 _all_borrow: Some(all_borrow),
                 _borrow: borrow,
                 last_insertion: 0,
@@ -78,6 +86,194 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
     }
 }
 
+impl<TRACK, T: Component> ViewMut<'_, T, TRACK> {
+    /// Wraps this view to iterate components that might not be present.\
+    /// See [`Optional`].
+    #[inline]
+    pub fn optional(&self) -> Optional<&Self> {
+        Optional(self)
+    }
+    /// Wraps this view to mutably iterate components that might not be present.\
+    /// See [`Optional`].
+    #[inline]
+    pub fn optional_mut(&mut self) -> Optional<&mut Self> {
+        Optional(self)
+    }
+    /// Returns a mutable slice of all the components in this storage.\
+    /// All components are flagged as modified.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        if self.sparse_set.is_tracking_modification {
+            self.sparse_set.modification_data.fill(self.current);
+        }
+
+        &mut self.sparse_set.data
+    }
+    /// Iterates the entities and components of this storage in slices of `chunk_size` elements.\
+    /// The last chunk can be shorter.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    #[track_caller]
+    #[inline]
+    pub fn iter_chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        Chunks {
+            entities: self.sparse_set.dense.chunks(chunk_size),
+            data: self.sparse_set.data.chunks(chunk_size),
+        }
+    }
+    /// Mutably iterates the entities and components of this storage in slices of `chunk_size` elements.\
+    /// The last chunk can be shorter.\
+    /// All components of a chunk are flagged as modified when the chunk is yielded.\
+    /// Tuples of views are chunked with [`IntoChunks::iter_chunks`](crate::IntoChunks::iter_chunks).
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for i in 0..10 {
+    ///     world.add_entity((Position(i as f32),));
+    /// }
+    ///
+    /// let mut positions = world.borrow::<ViewMut<Position>>().unwrap();
+    ///
+    /// for (_, positions) in positions.iter_chunks_mut(4) {
+    ///     for position in positions {
+    ///         position.0 *= 2.0;
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn iter_chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T> {
+        let SparseSet {
+            dense,
+            data,
+            modification_data,
+            is_tracking_modification,
+            ..
+        } = &mut *self.sparse_set;
+
+        ChunksMut {
+            entities: dense.chunks(chunk_size),
+            data: data.chunks_mut(chunk_size),
+            modification_data: is_tracking_modification
+                .then(|| modification_data.chunks_mut(chunk_size)),
+            current: self.current,
+        }
+    }
+    /// Returns all unordered combinations of `K` distinct components of this storage.\
+    /// Unlike [`apply_mut`](ViewMut::apply_mut) the entities don't have to be known in advance.
+    ///
+    /// The combinations are yielded one at a time with [`Combinations::fetch_next`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Velocity(1.0),));
+    /// world.add_entity((Velocity(2.0),));
+    /// world.add_entity((Velocity(3.0),));
+    ///
+    /// let mut velocities = world.borrow::<ViewMut<Velocity>>().unwrap();
+    ///
+    /// let mut combinations = velocities.iter_combinations_mut::<2>();
+    /// while let Some([mut a, mut b]) = combinations.fetch_next() {
+    ///     core::mem::swap(&mut a.0, &mut b.0);
+    /// }
+    /// ```
+    pub fn iter_combinations_mut<const K: usize>(
+        &mut self,
+    ) -> Combinations<FullRawWindowMut<'_, T>, K> {
+        self.iter().into_combinations()
+    }
+    /// Returns mutable references to the components of multiple distinct entities at once.
+    ///
+    /// ### Errors
+    ///
+    /// - MissingComponent - if one of the entities doesn't have a component in this storage.
+    /// - DuplicateId - if an entity is present multiple times in `entities`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Node {
+    ///     value: u32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let parent = world.add_entity((Node { value: 0 },));
+    /// let left = world.add_entity((Node { value: 1 },));
+    /// let right = world.add_entity((Node { value: 2 },));
+    ///
+    /// let mut nodes = world.borrow::<ViewMut<Node>>().unwrap();
+    ///
+    /// let [mut parent_node, left_node, right_node] = nodes.get_many_mut([parent, left, right]).unwrap();
+    /// parent_node.value = left_node.value + right_node.value;
+    ///
+    /// assert!(nodes.get_many_mut([left, left]).is_err());
+    /// ```
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        entities: [EntityId; N],
+    ) -> Result<[Mut<'_, T>; N], error::GetMany> {
+        let mut indices = [0; N];
+
+        for (i, &entity) in entities.iter().enumerate() {
+            let index = self
+                .index_of(entity)
+                .ok_or_else(|| error::MissingComponent {
+                    id: entity,
+                    name: core::any::type_name::<T>(),
+                })?;
+
+            if indices[..i].contains(&index) {
+                return Err(error::GetMany::DuplicateId(entity));
+            }
+
+            indices[i] = index;
+        }
+
+        let current = self.current;
+        let SparseSet {
+            data,
+            modification_data,
+            is_tracking_modification,
+            ..
+        } = &mut *self.sparse_set;
+        let is_tracking_modification = *is_tracking_modification;
+        let data = data.as_mut_ptr();
+        let modification_data = modification_data.as_mut_ptr();
+
+        // SAFE the indices are distinct and in bounds
+        Ok(indices.map(|index| unsafe {
+            Mut {
+                flag: is_tracking_modification.then(|| &mut *modification_data.add(index)),
+                current,
+                data: &mut *data.add(index),
+            }
+        }))
+    }
+}
+
 impl<'a, T: Component, TRACK> ViewMut<'a, T, TRACK>
 where
     Track<TRACK>: Tracking,
@@ -233,6 +429,13 @@ where
                 }
             })
     }
+    /// Returns an iteration source over the *deleted* components, it can be joined with other views.\
+    /// See [`Deleted`].
+    pub fn as_deleted(&self) -> Deleted<'_, T> {
+        Deleted::new(&self.sparse_set.deletion_data, |timestamp| {
+            is_track_within_bounds(timestamp, self.last_removal_or_deletion, self.current)
+        })
+    }
 }
 
 impl<TRACK, T: Component> ViewMut<'_, T, TRACK>
@@ -259,6 +462,11 @@ where
                 }
             })
     }
+    /// Returns an iteration source over the entities whose component was *removed*, it can be joined with other views.\
+    /// See [`Removed`].
+    pub fn as_removed(&self) -> Removed<'_, T> {
+        Removed::new(self.removed())
+    }
 }
 
 impl<TRACK, T: Component> ViewMut<'_, T, TRACK>
@@ -324,28 +532,39 @@ impl<'a, T: Component, TRACK> AsMut<Self> for ViewMut<'a, T, TRACK> {
 
 impl<T: fmt::Debug + Component, TRACK> fmt::Debug for ViewMut<'_, T, TRACK> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.sparse_set.fmt(f)
+        self.all_storages.with_names(|names| match names {
+            Some(names) => self.sparse_set.debug_with_names(names).fmt(f),
+            None => self.sparse_set.fmt(f),
+        })
     }
 }
 
 impl<'a, T: Component, TRACK> core::ops::Index<EntityId> for ViewMut<'a, T, TRACK> {
     type Output = T;
+    #[track_caller]
     #[inline]
     fn index(&self, entity: EntityId) -> &Self::Output {
-        self.get(entity).unwrap()
+        match self.get(entity) {
+            Ok(component) => component,
+            Err(err) => missing_component_panic(self.all_storages, err),
+        }
     }
 }
 
 impl<'a, T: Component, TRACK> core::ops::IndexMut<EntityId> for ViewMut<'a, T, TRACK> {
+    #[track_caller]
     #[inline]
     fn index_mut(&mut self, entity: EntityId) -> &mut Self::Output {
-        let index = self
-            .index_of(entity)
-            .ok_or_else(|| error::MissingComponent {
-                id: entity,
-                name: core::any::type_name::<T>(),
-            })
-            .unwrap();
+        let index = match self.index_of(entity) {
+            Some(index) => index,
+            None => missing_component_panic(
+                self.all_storages,
+                error::MissingComponent {
+                    id: entity,
+                    name: core::any::type_name::<T>(),
+                },
+            ),
+        };
 
         let SparseSet {
             data,
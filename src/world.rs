@@ -1,33 +1,44 @@
 use crate::all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetain};
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::WorldBorrow;
-use crate::component::Unique;
+use crate::component::{Component, Unique};
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get_component::GetComponent;
 use crate::info::WorkloadsTypeUsage;
 use crate::iter_component::{IntoIterRef, IterComponent};
+use crate::listener::ListenerId;
 use crate::memory_usage::WorldMemoryUsage;
+use crate::prefab::Prefab;
 use crate::public_transport::ShipyardRwLock;
 use crate::reserve::BulkEntityIter;
+use crate::scheduler::info::WorkloadInfo;
+#[cfg(feature = "std")]
+use crate::scheduler::info::WorkloadStats;
 use crate::scheduler::Label;
-use crate::scheduler::{AsLabel, Batches, Scheduler};
+use crate::scheduler::{
+    catch_system_panic, profile_system, record_run, record_skip, AsLabel, Batches, CaughtPanic,
+    FixedTimestep, FixedTimestepAlpha, Profiler, Scheduler, Time,
+};
 use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
 use crate::storage::{Storage, StorageId};
 use crate::system::System;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::views::EntitiesViewMut;
+use crate::views::{EntitiesViewMut, UniqueView, UniqueViewMut, ViewMut};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
-use core::sync::atomic::AtomicU32;
+use core::ops::Range;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
     pub(crate) all_storages: AtomicRefCell<AllStorages>,
     pub(crate) scheduler: AtomicRefCell<Scheduler>,
     counter: Arc<AtomicU32>,
+    deterministic: AtomicBool,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
 }
@@ -47,6 +58,7 @@ impl Default for World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            deterministic: AtomicBool::new(false),
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -76,6 +88,7 @@ impl World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            deterministic: AtomicBool::new(false),
             #[cfg(feature = "parallel")]
             thread_pool: None,
         }
@@ -98,6 +111,7 @@ impl World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            deterministic: AtomicBool::new(false),
             #[cfg(feature = "parallel")]
             thread_pool: Some(thread_pool),
         }
@@ -122,6 +136,7 @@ impl World {
             ),
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
+            deterministic: AtomicBool::new(false),
             #[cfg(feature = "parallel")]
             thread_pool: Some(thread_pool),
         }
@@ -131,6 +146,85 @@ impl World {
     pub fn remove_local_thread_pool(&mut self) -> Option<rayon::ThreadPool> {
         self.thread_pool.take()
     }
+    /// Runs `op` in the local [`ThreadPool`](rayon::ThreadPool) if there is one, on the current thread otherwise.
+    ///
+    /// Workloads already run in the local [`ThreadPool`](rayon::ThreadPool).\
+    /// Parallel iterators created outside of a workload, with [`World::run`] or [`World::borrow`] for example,
+    /// use the [`ThreadPool`](rayon::ThreadPool) of the thread they're created on.
+    /// Creating them in `op` makes them use the local [`ThreadPool`](rayon::ThreadPool).
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::ParallelIterator;
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let thread_pool = rayon::ThreadPoolBuilder::new()
+    ///     .num_threads(2)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut world = World::new_with_local_thread_pool(thread_pool);
+    ///
+    /// world.bulk_add_entity((0..100).map(U32));
+    ///
+    /// let sum: u32 = world.install(|| {
+    ///     world
+    ///         .run(|u32s: View<U32>| u32s.par_iter().with_min_len(10).map(|u| u.0).sum())
+    /// });
+    ///
+    /// assert_eq!(sum, 4950);
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        if let Some(thread_pool) = &self.thread_pool {
+            thread_pool.install(op)
+        } else {
+            op()
+        }
+    }
+    /// Makes workloads run the same way every time, even with the `parallel` feature.
+    ///
+    /// In deterministic mode:
+    /// - systems run one after the other, on a single thread, in the order they were added to the workload,
+    ///   moved only as much as needed to satisfy their ordering requirements.
+    /// - parallel iterators created by the systems split in half until chunks are at most [`DETERMINISTIC_CHUNK_LEN`] long
+    ///   and combine their results in a fixed order.
+    ///   Parallel iterators going through an adaptor controlling the split, like `zip` or `enumerate`, run on a single thread.
+    ///   This requires the `std` feature, without it parallel iterators split like outside of deterministic mode.
+    ///
+    /// Parallel iterators created on other threads, in a parallel iterator for example, are not affected.\
+    /// A single workload run can also be made deterministic with [`World::run_workload_deterministic`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn physics() {}
+    /// fn ai() {}
+    ///
+    /// let world = World::new();
+    /// world.set_deterministic(true);
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(physics)
+    ///     .with_system(ai)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Frame").unwrap();
+    /// ```
+    ///
+    /// [`DETERMINISTIC_CHUNK_LEN`]: crate::iter::DETERMINISTIC_CHUNK_LEN
+    pub fn set_deterministic(&self, deterministic: bool) {
+        self.deterministic.store(deterministic, Ordering::Release);
+    }
+    /// Returns `true` if workloads run in deterministic mode.\
+    /// See [`World::set_deterministic`].
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic.load(Ordering::Acquire)
+    }
     /// Adds a new unique storage, unique storages store a single value.  
     /// To access a unique storage value, use [`UniqueView`] or [`UniqueViewMut`].  
     ///
@@ -733,19 +827,79 @@ let i = world.run(sys1);
     /// - Storage borrow failed.
     /// - User error returned by system.
     pub fn run_workload<T>(&self, label: impl AsLabel<T>) -> Result<(), error::RunWorkload> {
+        let label = label.as_label();
+
+        self.run_workload_with_mode(&*label, self.is_deterministic())
+    }
+    /// Runs the `name` workload in deterministic mode, regardless of [`World::set_deterministic`].
+    ///
+    /// See [`World::set_deterministic`] for the execution order.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// fn movement(mut positions: ViewMut<Position>, velocities: View<Velocity>) {
+    ///     (&mut positions, &velocities)
+    ///         .iter()
+    ///         .for_each(|(mut position, velocity)| position.0 += velocity.0);
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.add_entity((Position(0.0), Velocity(1.0)));
+    ///
+    /// Workload::new("Replay")
+    ///     .with_system(movement)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload_deterministic("Replay").unwrap();
+    /// ```
+    pub fn run_workload_deterministic<T>(
+        &self,
+        label: impl AsLabel<T>,
+    ) -> Result<(), error::RunWorkload> {
+        let label = label.as_label();
+
+        self.run_workload_with_mode(&*label, true)
+    }
+    fn run_workload_with_mode(
+        &self,
+        label: &dyn Label,
+        deterministic: bool,
+    ) -> Result<(), error::RunWorkload> {
         let scheduler = self
             .scheduler
             .borrow()
             .map_err(|_| error::RunWorkload::Scheduler)?;
 
-        let label = label.as_label();
-        let batches = scheduler.workload(&*label)?;
+        let batches = scheduler.workload(label)?;
 
         self.run_batches(
             &scheduler.systems,
             &scheduler.system_names,
             batches,
-            &*label,
+            label,
+            scheduler.profiler.as_ref(),
+            deterministic,
         )
     }
     /// Returns `true` if the world contains the `name` workload.
@@ -775,6 +929,164 @@ let i = world.run(sys1);
 
         self.scheduler.borrow().unwrap().contains_workload(&*label)
     }
+    /// Enables or disables a system in all workloads.\
+    /// `label` can be the system's function or its name.
+    ///
+    /// Disabled systems are skipped, as if their `run_if` returned `false`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - No system with this label in the `World`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueViewMut, UniqueView, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Count(u32);
+    ///
+    /// fn count(mut count: UniqueViewMut<Count>) {
+    ///     count.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Count(0));
+    ///
+    /// Workload::new("Count")
+    ///     .with_system(count)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.set_system_enabled(count, false).unwrap();
+    /// world.run_workload("Count").unwrap();
+    /// assert!(!world.workload_info("Count").unwrap().batch_info[0].systems.0.as_ref().unwrap().enabled);
+    ///
+    /// world.set_system_enabled(count, true).unwrap();
+    /// world.run_workload("Count").unwrap();
+    ///
+    /// assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+    /// ```
+    pub fn set_system_enabled<T>(
+        &self,
+        label: impl AsLabel<T>,
+        enabled: bool,
+    ) -> Result<(), error::SetSystemEnabled> {
+        let label = label.as_label();
+
+        self.scheduler
+            .borrow()
+            .map_err(|_| error::SetSystemEnabled::Borrow)?
+            .set_system_enabled(&*label, enabled)
+    }
+    /// Returns the information of the `name` workload, with the up to date [`enabled`] state of its systems.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// [`enabled`]: crate::info::SystemInfo::enabled
+    #[track_caller]
+    pub fn workload_info<T>(&self, name: impl AsLabel<T>) -> Option<WorkloadInfo> {
+        let label = name.as_label();
+
+        self.scheduler.borrow().unwrap().workload_info(&*label)
+    }
+    /// Starts collecting statistics about the workloads ran using [`run_workload`] and [`run_default`].\
+    /// Does nothing if profiling is already enabled.
+    ///
+    /// Statistics can be retrieved with [`workload_stats`].
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// [`run_workload`]: World::run_workload
+    /// [`run_default`]: World::run_default
+    /// [`workload_stats`]: World::workload_stats
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn enable_profiling(&self) {
+        let mut scheduler = self.scheduler.borrow_mut().unwrap();
+
+        if scheduler.profiler.is_none() {
+            scheduler.profiler = Some(Profiler::default());
+        }
+    }
+    /// Stops collecting statistics about the workloads and discards the ones already collected.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn disable_profiling(&self) {
+        self.scheduler.borrow_mut().unwrap().profiler = None;
+    }
+    /// Returns the statistics collected for the `label` workload since profiling was enabled.\
+    /// Returns `None` if profiling is disabled or the workload didn't run since.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Game")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.enable_profiling();
+    /// world.run_workload("Game").unwrap();
+    ///
+    /// let stats = world.workload_stats("Game").unwrap();
+    /// assert_eq!(stats.runs, 1);
+    /// assert_eq!(stats.systems.len(), 2);
+    ///
+    /// let slowest = stats.systems.iter().max_by_key(|system| system.max_time).unwrap();
+    /// println!("slowest system: {:?}", slowest.name);
+    /// ```
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn workload_stats<T>(&self, label: impl AsLabel<T>) -> Option<WorkloadStats> {
+        let label = label.as_label();
+
+        self.scheduler
+            .borrow()
+            .unwrap()
+            .profiler
+            .as_ref()?
+            .workload_stats(&*label)
+    }
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches(
         &self,
@@ -782,6 +1094,8 @@ let i = world.run(sys1);
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         workload_name: &dyn Label,
+        profiler: Option<&Profiler>,
+        deterministic: bool,
     ) -> Result<(), error::RunWorkload> {
         if let Some(run_if) = &batches.run_if {
             if !run_if
@@ -792,14 +1106,143 @@ let i = world.run(sys1);
             }
         }
 
+        if let Some(fixed_timestep) = &batches.fixed_timestep {
+            let (steps, alpha) = self.advance_fixed_timestep(fixed_timestep, workload_name)?;
+
+            for _ in 0..steps {
+                self.run_batches_once(
+                    systems,
+                    system_names,
+                    batches,
+                    workload_name,
+                    profiler,
+                    deterministic,
+                )?;
+            }
+
+            self.set_fixed_timestep_alpha(alpha, workload_name)?;
+        } else {
+            self.run_batches_once(
+                systems,
+                system_names,
+                batches,
+                workload_name,
+                profiler,
+                deterministic,
+            )?;
+        }
+
+        self.end_workload_run(workload_name)
+    }
+    /// Starts a new event update at the end of a workload run.
+    fn end_workload_run(&self, workload_name: &dyn Label) -> Result<(), error::RunWorkload> {
+        let all_storages = self.all_storages.borrow().map_err(|err| {
+            error::RunWorkload::Run((
+                workload_name.dyn_clone(),
+                error::Run::GetStorage(error::GetStorage::AllStoragesBorrow(err)),
+            ))
+        })?;
+
+        all_storages.update_events();
+
+        Ok(())
+    }
+    #[allow(clippy::type_complexity)]
+    fn run_batches_once(
+        &self,
+        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        system_names: &[Box<dyn Label>],
+        batches: &Batches,
+        workload_name: &dyn Label,
+        profiler: Option<&Profiler>,
+        deterministic: bool,
+    ) -> Result<(), error::RunWorkload> {
+        record_run(profiler, workload_name);
+
+        let caught_panic = CaughtPanic::default();
+
         #[cfg(feature = "parallel")]
-        {
-            self.run_batches_parallel(systems, system_names, batches, workload_name)
+        if deterministic {
+            let run_sequential = || {
+                crate::iter::deterministic(|| {
+                    self.run_batches_sequential(
+                        systems,
+                        system_names,
+                        batches,
+                        workload_name,
+                        profiler,
+                        &caught_panic,
+                    )
+                })
+            };
+
+            if let Some(thread_pool) = &self.thread_pool {
+                thread_pool.scope(|_| run_sequential())?;
+            } else {
+                run_sequential()?;
+            }
+        } else {
+            self.run_batches_parallel(
+                systems,
+                system_names,
+                batches,
+                workload_name,
+                profiler,
+                &caught_panic,
+            )?;
         }
 
+        // Without parallel feature workloads always run sequentially
         #[cfg(not(feature = "parallel"))]
         {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+            let _ = deterministic;
+
+            self.run_batches_sequential(
+                systems,
+                system_names,
+                batches,
+                workload_name,
+                profiler,
+                &caught_panic,
+            )?;
+        }
+
+        caught_panic.into_result()
+    }
+    /// Returns the number of steps the workload has to run and the leftover alpha.
+    fn advance_fixed_timestep(
+        &self,
+        fixed_timestep: &FixedTimestep,
+        workload_name: &dyn Label,
+    ) -> Result<(u32, f32), error::RunWorkload> {
+        let time = self.borrow::<UniqueView<'_, Time>>().map_err(|err| {
+            error::RunWorkload::Run((workload_name.dyn_clone(), error::Run::GetStorage(err)))
+        })?;
+
+        fixed_timestep
+            .advance(&time)
+            .map_err(|_| error::RunWorkload::Scheduler)
+    }
+    fn set_fixed_timestep_alpha(
+        &self,
+        alpha: f32,
+        workload_name: &dyn Label,
+    ) -> Result<(), error::RunWorkload> {
+        match self.borrow::<UniqueViewMut<'_, FixedTimestepAlpha>>() {
+            Ok(mut fixed_timestep_alpha) => {
+                fixed_timestep_alpha.0 = alpha;
+
+                Ok(())
+            }
+            Err(error::GetStorage::MissingStorage { .. }) => {
+                self.add_unique(FixedTimestepAlpha(alpha));
+
+                Ok(())
+            }
+            Err(err) => Err(error::RunWorkload::Run((
+                workload_name.dyn_clone(),
+                error::Run::GetStorage(err),
+            ))),
         }
     }
     #[cfg(feature = "parallel")]
@@ -809,7 +1252,9 @@ let i = world.run(sys1);
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        profiler: Option<&Profiler>,
+        caught_panic: &CaughtPanic,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
@@ -821,7 +1266,9 @@ let i = world.run(sys1);
                 let mut result = Ok(());
                 let run_if = (
                     if let Some(run_if_index) = batches_run_if.0 {
-                        if let Some(run_if) = &batches.sequential_run_if[run_if_index] {
+                        if !batches.is_enabled(run_if_index) {
+                            false
+                        } else if let Some(run_if) = &batches.sequential_run_if[run_if_index] {
                             (run_if)(self).map_err(|err| {
                                 error::RunWorkload::Run((
                                     system_names[batch.0.unwrap()].clone(),
@@ -838,7 +1285,9 @@ let i = world.run(sys1);
                         .1
                         .iter()
                         .map(|run_if_index| {
-                            if let Some(run_if) = &batches.sequential_run_if[*run_if_index] {
+                            if !batches.is_enabled(*run_if_index) {
+                                Ok(false)
+                            } else if let Some(run_if) = &batches.sequential_run_if[*run_if_index] {
                                 (run_if)(self).map_err(|err| {
                                     error::RunWorkload::Run((
                                         system_names[batches.sequential[*run_if_index]].clone(),
@@ -855,23 +1304,31 @@ let i = world.run(sys1);
                 rayon::in_place_scope(|scope| {
                     scope.spawn(|_| {
                         if batch.1.len() == 1 {
+                            let index = batch.1[0];
+
                             if !run_if.1[0] {
+                                record_skip(profiler, workload_name, index, &*system_names[index]);
+
                                 return;
                             }
 
                             #[cfg(feature = "tracing")]
-                            let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[batch.1[0]]);
+                            let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
                             #[cfg(feature = "tracing")]
                             let _system_span = system_span.enter();
 
-                            result = systems[batch.1[0]](self).map_err(|err| {
-                                error::RunWorkload::Run((system_names[batch.1[0]].clone(), err))
+                            result = catch_system_panic(batches, batches_run_if.1[0], &*system_names[index], caught_panic, || {
+                                profile_system(profiler, workload_name, index, &*system_names[index], || systems[index](self)).map_err(|err| {
+                                    error::RunWorkload::Run((system_names[index].clone(), err))
+                                })
                             });
                         } else {
                             use rayon::prelude::*;
 
-                            result = batch.1.par_iter().zip(run_if.1).try_for_each(|(&index, should_run)| {
+                            result = batch.1.par_iter().zip(&batches_run_if.1).zip(run_if.1).try_for_each(|((&index, &run_if_index), should_run)| {
                                 if !should_run {
+                                    record_skip(profiler, workload_name, index, &*system_names[index]);
+
                                     return Ok(());
                                 }
 
@@ -880,23 +1337,42 @@ let i = world.run(sys1);
                                 #[cfg(feature = "tracing")]
                                 let _system_span = system_span.enter();
 
-                                (systems[index])(self).map_err(|err| {
-                                    error::RunWorkload::Run((system_names[index].clone(), err))
+                                catch_system_panic(batches, run_if_index, &*system_names[index], caught_panic, || {
+                                    profile_system(profiler, workload_name, index, &*system_names[index], || (systems[index])(self)).map_err(|err| {
+                                        error::RunWorkload::Run((system_names[index].clone(), err))
+                                    })
                                 })
                             });
                         }
                     });
 
-                    if let Some(index) = batch.0 {
+                    if let (Some(index), Some(run_if_index)) = (batch.0, batches_run_if.0) {
                         if run_if.0 {
                             #[cfg(feature = "tracing")]
                             let system_span = tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
                             #[cfg(feature = "tracing")]
                             let _system_span = system_span.enter();
 
-                            systems[index](self).map_err(|err| {
-                                error::RunWorkload::Run((system_names[index].clone(), err))
-                            })?;
+                            catch_system_panic(
+                                batches,
+                                run_if_index,
+                                &*system_names[index],
+                                caught_panic,
+                                || {
+                                    profile_system(
+                                        profiler,
+                                        workload_name,
+                                        index,
+                                        &*system_names[index],
+                                        || systems[index](self),
+                                    )
+                                    .map_err(|err| {
+                                        error::RunWorkload::Run((system_names[index].clone(), err))
+                                    })
+                                },
+                            )?;
+                        } else {
+                            record_skip(profiler, workload_name, index, &*system_names[index]);
                         }
                     }
 
@@ -916,14 +1392,16 @@ let i = world.run(sys1);
             run_batch()
         }
     }
-    #[cfg(not(feature = "parallel"))]
+    /// Runs the systems one after the other in `batches.sequential` order.
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches_sequential(
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        profiler: Option<&Profiler>,
+        caught_panic: &CaughtPanic,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
@@ -934,25 +1412,48 @@ let i = world.run(sys1);
             .sequential
             .iter()
             .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, run_if)| {
+            .enumerate()
+            .try_for_each(|(system_position, (&index, run_if))| {
+                if !batches.is_enabled(system_position) {
+                    record_skip(profiler, workload_name, index, &*system_names[index]);
+
+                    return Ok(());
+                }
+
                 if let Some(run_if) = run_if.as_ref() {
                     let should_run = (run_if)(self).map_err(|err| {
                         error::RunWorkload::Run((system_names[index].clone(), err))
                     })?;
 
                     if !should_run {
+                        record_skip(profiler, workload_name, index, &*system_names[index]);
+
                         return Ok(());
                     }
                 }
 
                 #[cfg(feature = "tracing")]
                 let system_span =
-                    tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_name);
+                    tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
                 #[cfg(feature = "tracing")]
                 let _system_span = system_span.enter();
 
-                (systems[index])(self)
-                    .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+                catch_system_panic(
+                    batches,
+                    system_position,
+                    &*system_names[index],
+                    caught_panic,
+                    || {
+                        profile_system(
+                            profiler,
+                            workload_name,
+                            index,
+                            &*system_names[index],
+                            || (systems[index])(self),
+                        )
+                        .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+                    },
+                )
             })
     }
     /// Run the default workload if there is one.
@@ -979,6 +1480,8 @@ let i = world.run(sys1);
                 &scheduler.system_names,
                 scheduler.default_workload(),
                 &scheduler.default,
+                scheduler.profiler.as_ref(),
+                self.is_deterministic(),
             )?
         }
         Ok(())
@@ -1021,13 +1524,12 @@ let i = world.run(sys1);
 
     #[inline]
     pub(crate) fn get_current(&self) -> u32 {
-        self.counter
-            .fetch_add(1, core::sync::atomic::Ordering::Acquire)
+        self.counter.fetch_add(1, Ordering::Acquire)
     }
 
     /// Returns a timestamp used to clear tracking information.
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
-        TrackingTimestamp(self.counter.load(core::sync::atomic::Ordering::Acquire))
+        TrackingTimestamp(self.counter.load(Ordering::Acquire))
     }
 }
 
@@ -1205,6 +1707,105 @@ impl World {
     pub fn strip(&mut self, entity: EntityId) {
         self.all_storages.get_mut().strip(entity);
     }
+    /// Returns the [`StorageId`] of all storages with a component for `entity`.\
+    /// Storages that can't be borrowed are skipped, use [`components_of_mut`](World::components_of_mut) to visit all of them.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - All storages (shared), one at a time
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    /// world.add_entity((USIZE(1),));
+    ///
+    /// let storages = world.components_of(entity).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    #[inline]
+    pub fn components_of(&self, entity: EntityId) -> impl Iterator<Item = StorageId> {
+        self.all_storages.borrow().unwrap().components_of(entity)
+    }
+    /// Returns the [`StorageId`] of all storages with a component for `entity`.\
+    /// Unlike [`components_of`](World::components_of), no storage is borrowed so none can be skipped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    ///
+    /// let storages = world.components_of_mut(entity).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+    /// ```
+    #[inline]
+    pub fn components_of_mut(&mut self, entity: EntityId) -> impl Iterator<Item = StorageId> {
+        self.all_storages.get_mut().components_of_mut(entity)
+    }
+    /// Returns the name of the storage identified by `storage_id`, if present.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - Storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((U32(0),));
+    ///
+    /// let name = world
+    ///     .storage_name(StorageId::of::<SparseSet<U32>>())
+    ///     .unwrap();
+    ///
+    /// assert!(name.contains("U32"));
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    #[inline]
+    pub fn storage_name(&self, storage_id: StorageId) -> Option<Cow<'static, str>> {
+        self.all_storages.borrow().unwrap().storage_name(storage_id)
+    }
     /// Deletes all entities with any of the given components.  
     /// The storage's type has to be used and not the component.  
     /// `SparseSet` is the default storage.
@@ -1286,6 +1887,42 @@ impl World {
     pub fn clear(&mut self) {
         self.all_storages.get_mut().clear();
     }
+    /// Starts a new event update, events sent before the previous update expire.\
+    /// Each workload run already starts one when it ends, this is only needed to expire events outside of workloads.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EventReader, EventWriter, World};
+    ///
+    /// struct Collision(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut collisions: EventWriter<Collision>| {
+    ///     collisions.send(Collision(0));
+    /// });
+    ///
+    /// world.update_events();
+    /// world.run(|collisions: EventReader<Collision>| assert_eq!(collisions.len(), 1));
+    ///
+    /// world.update_events();
+    /// world.run(|collisions: EventReader<Collision>| assert!(collisions.is_empty()));
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    #[inline]
+    pub fn update_events(&self) {
+        self.all_storages.borrow().unwrap().update_events();
+    }
     /// Clear all deletion and removal tracking data.
     pub fn clear_all_removed_and_deleted(&mut self) {
         self.all_storages.get_mut().clear_all_removed_and_deleted()
@@ -1300,12 +1937,157 @@ impl World {
             .clear_all_removed_and_deleted_older_than_timestamp(timestamp)
     }
     /// Make the given entity alive.  
-    /// Does nothing if an entity with a greater generation is already at this index.  
+    /// Does nothing if an entity with a greater generation is already at this index
+    /// or if the index is part of the [local range](crate::Entities::set_local_range).  
     /// Returns `true` if the entity is successfully spawned.
     #[inline]
     pub fn spawn(&mut self, entity: EntityId) -> bool {
         self.all_storages.get_mut().spawn(entity)
     }
+    /// Reserves `range` for locally created entities.  
+    /// See [`Entities::set_local_range`](crate::Entities::set_local_range) for more details.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is empty or too large to fit in an `EntityId`.
+    #[inline]
+    #[track_caller]
+    pub fn set_local_entity_range(&mut self, range: Range<u64>) {
+        self.all_storages.get_mut().set_local_entity_range(range);
+    }
+    /// Removes the local entity range, new entities can use any index again.
+    #[inline]
+    pub fn remove_local_entity_range(&mut self) {
+        self.all_storages.get_mut().remove_local_entity_range();
+    }
+    /// Returns the first entity with the given [`Name`].
+    /// Names are not indexed, this is a linear search.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - [`Name`] storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - [`Name`] storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Name, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let player = world.add_entity((Name::new("player_ship"),));
+    ///
+    /// assert_eq!(world.entity_by_name("player_ship"), Some(player));
+    /// assert_eq!(world.entity_by_name("enemy_ship"), None);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`Name`]: crate::Name
+    #[track_caller]
+    pub fn entity_by_name(&self, name: &str) -> Option<EntityId> {
+        self.all_storages.borrow().unwrap().entity_by_name(name)
+    }
+    /// Registers `prefab` under `name`, replacing any prefab with the same name.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// world.add_prefab("u32", prefab);
+    /// ```
+    #[inline]
+    pub fn add_prefab(&mut self, name: impl Into<Cow<'static, str>>, prefab: Prefab) {
+        self.all_storages.get_mut().add_prefab(name, prefab);
+    }
+    /// Returns `true` if a prefab is registered under `name`.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[track_caller]
+    pub fn contains_prefab(&self, name: &str) -> bool {
+        self.all_storages.borrow().unwrap().contains_prefab(name)
+    }
+    /// Creates new entities from the prefab registered under `name` and returns the `EntityId` of its root.
+    ///
+    /// ### Errors
+    ///
+    /// - No prefab is registered under `name`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// world.add_prefab("u32", prefab);
+    ///
+    /// let entity = world.spawn_prefab("u32").unwrap();
+    /// ```
+    #[inline]
+    pub fn spawn_prefab(&mut self, name: &str) -> Result<EntityId, error::SpawnPrefab> {
+        self.all_storages.get_mut().spawn_prefab(name)
+    }
+    /// Creates `count` copies of the prefab registered under `name`.\
+    /// Returns an iterator yielding the `EntityId` of each copy's root.
+    ///
+    /// ### Errors
+    ///
+    /// - No prefab is registered under `name`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Prefab, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let mut prefab = Prefab::new();
+    /// prefab.add_component(prefab.root(), (U32(0),));
+    ///
+    /// world.add_prefab("u32", prefab);
+    ///
+    /// let new_entities = world.bulk_spawn_prefab("u32", 10).unwrap();
+    /// assert_eq!(new_entities.len(), 10);
+    /// ```
+    #[inline]
+    pub fn bulk_spawn_prefab(
+        &mut self,
+        name: &str,
+        count: usize,
+    ) -> Result<BulkEntityIter<'_>, error::SpawnPrefab> {
+        self.all_storages.get_mut().bulk_spawn_prefab(name, count)
+    }
     /// Displays storages memory information.
     pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
         WorldMemoryUsage(self)
@@ -1538,7 +2320,62 @@ for (i, j) in &mut iter {
         }
     }
 
-    /// Sets the on entity deletion callback.
+    /// Registers a callback called each time an entity is created.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn on_creation(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.on_creation(f)
+    }
+    /// Registers a callback called each time an entity is deleted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn on_deletion(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.on_deletion(f)
+    }
+    /// Registers a callback called each time an entity is made alive with `spawn`.\
+    /// Returns an id that can be used to unregister the callback with [`remove_entity_listener`](Self::remove_entity_listener).
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn on_spawn(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) -> ListenerId {
+        let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
+
+        entities.on_spawn(f)
+    }
+    /// Unregisters an entity creation, deletion or spawn callback.\
+    /// Returns `true` if the callback was registered.
     ///
     /// ### Borrows
     ///
@@ -1550,10 +2387,72 @@ for (i, j) in &mut iter {
     /// - AllStorages borrow failed.
     /// - Entities borrow failed.
     #[track_caller]
-    pub fn on_deletion(&self, f: impl FnMut(EntityId) + Send + Sync + 'static) {
+    pub fn remove_entity_listener(&self, id: ListenerId) -> bool {
         let mut entities = self.borrow::<EntitiesViewMut<'_>>().unwrap();
 
-        entities.on_deletion(f);
+        entities.remove_listener(id)
+    }
+    /// Registers a callback called each time a `T` component is inserted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_component_listener`](Self::remove_component_listener).\
+    /// See [`SparseSet::on_insert`](crate::SparseSet::on_insert) for more details.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn on_insert<T: Send + Sync + Component>(
+        &self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) -> ListenerId {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.on_insert(f)
+    }
+    /// Registers a callback called each time a `T` component is removed or deleted.\
+    /// Returns an id that can be used to unregister the callback with [`remove_component_listener`](Self::remove_component_listener).\
+    /// See [`SparseSet::on_remove`](crate::SparseSet::on_remove) for more details.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn on_remove<T: Send + Sync + Component>(
+        &self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) -> ListenerId {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.on_remove(f)
+    }
+    /// Unregisters a `T` component insertion or removal callback.\
+    /// Returns `true` if the callback was registered.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - `T` storage borrow failed.
+    #[track_caller]
+    pub fn remove_component_listener<T: Send + Sync + Component>(&self, id: ListenerId) -> bool {
+        let mut sparse_set = self.borrow::<ViewMut<'_, T>>().unwrap();
+
+        sparse_set.remove_listener(id)
     }
 }
 
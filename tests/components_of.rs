@@ -0,0 +1,121 @@
+use core::any::type_name;
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Counter(u32);
+impl Unique for Counter {}
+
+#[test]
+fn components_of() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Counter(0));
+
+    let entity0 = world.add_entity((USIZE(0), U32(1)));
+    let entity1 = world.add_entity((U32(2),));
+    let entity2 = world.add_entity(());
+
+    let mut storages = world.components_of(entity0).collect::<Vec<_>>();
+    storages.sort_unstable();
+
+    let mut expected = vec![
+        StorageId::of::<SparseSet<USIZE>>(),
+        StorageId::of::<SparseSet<U32>>(),
+    ];
+    expected.sort_unstable();
+
+    assert_eq!(storages, expected);
+    assert_eq!(
+        world.components_of(entity1).collect::<Vec<_>>(),
+        vec![StorageId::of::<SparseSet<U32>>()]
+    );
+    assert_eq!(world.components_of(entity2).count(), 0);
+
+    world.delete_component::<U32>(entity1);
+
+    assert_eq!(world.components_of(entity1).count(), 0);
+
+    world.delete_entity(entity0);
+
+    assert_eq!(world.components_of(entity0).count(), 0);
+}
+
+#[test]
+fn storage_name() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let entity = world.add_entity((USIZE(0),));
+
+    let storage_id = world.components_of(entity).next().unwrap();
+
+    assert_eq!(
+        world.storage_name(storage_id).as_deref(),
+        Some(type_name::<SparseSet<USIZE>>())
+    );
+    assert_eq!(world.storage_name(StorageId::of::<SparseSet<U32>>()), None);
+}
+
+#[test]
+fn shared_access() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let entity = world.add_entity((USIZE(0), U32(1)));
+
+    world.run(|all_storages: AllStoragesView, usizes: View<USIZE>| {
+        assert_eq!(usizes[entity], USIZE(0));
+        assert_eq!(all_storages.components_of(entity).count(), 2);
+        assert_eq!(
+            all_storages
+                .storage_name(StorageId::of::<SparseSet<U32>>())
+                .as_deref(),
+            Some(type_name::<SparseSet<U32>>())
+        );
+    });
+}
+
+#[test]
+fn exclusively_borrowed_storage() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let entity = world.add_entity((USIZE(0), U32(0)));
+
+    let _usizes = world.borrow::<ViewMut<USIZE>>().unwrap();
+
+    let storages = world.components_of(entity).collect::<Vec<_>>();
+
+    assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+}
+
+#[test]
+fn components_of_mut() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let entity = world.add_entity((USIZE(0), U32(0)));
+    world.add_entity((U32(1),));
+    world.add_unique(Counter(1));
+
+    let mut storages = world.components_of_mut(entity).collect::<Vec<_>>();
+    storages.sort_unstable();
+
+    let mut expected = vec![
+        StorageId::of::<SparseSet<USIZE>>(),
+        StorageId::of::<SparseSet<U32>>(),
+    ];
+    expected.sort_unstable();
+
+    assert_eq!(storages, expected);
+
+    world.delete_component::<(USIZE,)>(entity);
+
+    let storages = world.components_of_mut(entity).collect::<Vec<_>>();
+
+    assert_eq!(storages, vec![StorageId::of::<SparseSet<U32>>()]);
+}
@@ -124,3 +124,41 @@ fn old_id() {
         assert!(u32s.get(entity1).is_err());
     });
 }
+
+#[test]
+fn get_many_mut() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct U32(u32);
+    impl Component for U32 {}
+
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_modification::<U32>();
+
+    let entity0 = world.add_entity((U32(0),));
+    let entity1 = world.add_entity((U32(1),));
+    let entity2 = world.add_entity((U32(2),));
+    let entity3 = world.add_entity(());
+
+    world.run(|mut u32s: ViewMut<U32, track::Modification>| {
+        let [mut u0, u2] = u32s.get_many_mut([entity0, entity2]).unwrap();
+        u0.0 += u2.0;
+
+        assert_eq!(
+            u32s.get_many_mut([entity1, entity0, entity1]).err(),
+            Some(error::GetMany::DuplicateId(entity1))
+        );
+        assert_eq!(
+            u32s.get_many_mut([entity1, entity3]).err(),
+            Some(error::GetMany::MissingComponent(error::MissingComponent {
+                id: entity3,
+                name: core::any::type_name::<U32>(),
+            }))
+        );
+        assert_eq!(u32s.get_many_mut::<0>([]).map(|u| u.len()), Ok(0));
+    });
+
+    world.run(|u32s: View<U32, track::Modification>| {
+        assert_eq!(u32s[entity0], U32(2));
+        assert_eq!(u32s.modified().iter().count(), 1);
+    });
+}
@@ -0,0 +1,132 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct C(u32);
+impl Component for C {}
+
+#[test]
+fn basic() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0), B(0), C(0)));
+    world.add_entity((C(1),));
+    world.add_entity((A(2), C(2)));
+    world.add_entity((B(3),));
+
+    let (a, b, c) = world.borrow::<(View<A>, View<B>, View<C>)>().unwrap();
+
+    assert_eq!(
+        AnyOf::new((&a, &b, &c)).iter().collect::<Vec<_>>(),
+        [
+            (Some(&A(0)), Some(&B(0)), Some(&C(0))),
+            (Some(&A(2)), None, Some(&C(2))),
+            (None, Some(&B(3)), None),
+            (None, None, Some(&C(1))),
+        ]
+    );
+    assert_eq!(
+        AnyOf::new((&b, &c)).iter().collect::<Vec<_>>(),
+        [
+            (Some(&B(0)), Some(&C(0))),
+            (Some(&B(3)), None),
+            (None, Some(&C(1))),
+            (None, Some(&C(2))),
+        ]
+    );
+}
+
+#[test]
+fn passenger() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0), B(0)));
+    world.add_entity((A(1),));
+    world.add_entity((A(2), C(2)));
+
+    let (a, b, c) = world.borrow::<(View<A>, View<B>, View<C>)>().unwrap();
+
+    assert_eq!(
+        (&a, AnyOf::new((&b, &c))).iter().collect::<Vec<_>>(),
+        [(&A(0), (Some(&B(0)), None)), (&A(2), (None, Some(&C(2))))]
+    );
+}
+
+#[test]
+fn mutable() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0), B(0)));
+    world.add_entity((B(1),));
+
+    let (mut a, b) = world.borrow::<(ViewMut<A>, View<B>)>().unwrap();
+
+    for (a, b) in AnyOf::new((&mut a, &b)).iter() {
+        if let (Some(mut a), Some(b)) = (a, b) {
+            a.0 += b.0 + 10;
+        }
+    }
+
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), [A(10)]);
+}
+
+#[test]
+fn with_id() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0),));
+    let e1 = world.add_entity((B(1),));
+    let e2 = world.add_entity((A(2), B(2)));
+
+    let (a, b) = world.borrow::<(View<A>, View<B>)>().unwrap();
+
+    assert_eq!(
+        AnyOf::new((&a, &b))
+            .iter()
+            .with_id()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>(),
+        [e0, e2, e1]
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    for i in 0..3000 {
+        match i % 4 {
+            0 => world.add_entity((A(i),)),
+            1 => world.add_entity((B(i), C(i))),
+            2 => world.add_entity((A(i), C(i))),
+            _ => world.add_entity((C(i),)),
+        };
+    }
+
+    let (a, b, c) = world.borrow::<(View<A>, View<B>, View<C>)>().unwrap();
+
+    let ids = |(a, b, c): (Option<&A>, Option<&B>, Option<&C>)| {
+        a.map(|a| a.0)
+            .or(b.map(|b| b.0))
+            .or(c.map(|c| c.0))
+            .unwrap()
+    };
+
+    let mut par = AnyOf::new((&a, &b, &c))
+        .par_iter()
+        .map(ids)
+        .collect::<Vec<_>>();
+    par.sort_unstable();
+
+    assert_eq!(par, (0..3000).collect::<Vec<_>>());
+}
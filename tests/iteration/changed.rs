@@ -0,0 +1,227 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct C(u32);
+impl Component for C {}
+
+#[test]
+fn changed() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_insertion::<A>();
+    world.track_modification::<B>();
+
+    let e0 = world.add_entity((A(0), B(0), C(0)));
+    let e1 = world.add_entity((A(1), B(1), C(1)));
+    let e2 = world.add_entity((B(2), C(2)));
+
+    world.run(
+        |a: ViewMut<A, track::Insertion>, mut b: ViewMut<B, track::Modification>| {
+            a.clear_all_inserted();
+
+            b[e1].0 += 10;
+            b[e2].0 += 10;
+        },
+    );
+
+    let e3 = world.add_entity((A(3), B(3)));
+
+    let (a, b, c) = world
+        .borrow::<(
+            View<A, track::Insertion>,
+            View<B, track::Modification>,
+            View<C>,
+        )>()
+        .unwrap();
+
+    let mut changed = Changed::new((&a, &b)).iter().with_id().collect::<Vec<_>>();
+    changed.sort_unstable_by_key(|(id, _)| *id);
+    assert_eq!(changed, [(e1, (&A(1), &B(11))), (e3, (&A(3), &B(3)))]);
+
+    assert_eq!(
+        (Changed::new((&a, &b)), &c)
+            .iter()
+            .ids()
+            .collect::<Vec<_>>(),
+        [e1]
+    );
+    assert_eq!(
+        (&c, Changed::new((&b,))).iter().ids().collect::<Vec<_>>(),
+        [e1, e2]
+    );
+    assert!(!(Changed::new((&a, &b)), &c).iter().ids().any(|id| id == e0));
+}
+
+#[test]
+fn since() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_modification::<(A, B)>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1), B(1)));
+    world.add_entity((A(2), B(2)));
+
+    let timestamp = world.get_tracking_timestamp();
+
+    world.run(|mut a: ViewMut<A, track::Modification>| {
+        a[e0].0 += 10;
+    });
+
+    let timestamp2 = world.get_tracking_timestamp();
+
+    world.run(|mut b: ViewMut<B, track::Modification>| {
+        b[e1].0 += 10;
+    });
+
+    let (mut a, b) = world
+        .borrow::<(
+            ViewMut<A, track::Modification>,
+            View<B, track::Modification>,
+        )>()
+        .unwrap();
+
+    assert_eq!(
+        Changed::new((&a, &b))
+            .since(timestamp)
+            .iter()
+            .ids()
+            .collect::<Vec<_>>(),
+        [e0, e1]
+    );
+    assert_eq!(
+        Changed::new((&a, &b))
+            .since(timestamp2)
+            .iter()
+            .ids()
+            .collect::<Vec<_>>(),
+        [e1]
+    );
+
+    for (mut a, b) in Changed::new((&mut a, &b)).since(timestamp2).iter() {
+        a.0 = b.0;
+    }
+    assert_eq!(a[e1], A(11));
+}
+
+#[test]
+fn removed() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_removal::<A>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1),));
+    let e2 = world.add_entity((A(2), B(2)));
+    let e3 = world.add_entity((B(3),));
+
+    world.remove::<A>(e2);
+    world.remove::<A>(e1);
+    world.remove::<A>(e1);
+
+    let (a, b) = world
+        .borrow::<(View<A, track::Removal>, View<B>)>()
+        .unwrap();
+
+    let mut removed = a.as_removed().iter().ids().collect::<Vec<_>>();
+    removed.sort_unstable();
+    assert_eq!(removed, [e1, e2]);
+
+    assert_eq!((a.as_removed(), &b).iter().ids().collect::<Vec<_>>(), [e2]);
+    assert_eq!(
+        (&b, a.as_removed())
+            .iter_by::<B>()
+            .ids()
+            .collect::<Vec<_>>(),
+        [e2]
+    );
+    assert!(!(&b, a.as_removed())
+        .iter()
+        .ids()
+        .any(|id| id == e0 || id == e3));
+}
+
+#[test]
+fn deleted() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_deletion::<A>();
+
+    let e0 = world.add_entity((A(0),));
+    let e1 = world.add_entity((A(1),));
+    let e2 = world.add_entity((A(2),));
+
+    world.delete_component::<(A,)>(e0);
+    world.delete_component::<(A,)>(e2);
+    world.add_component(e2, (B(2),));
+    world.add_component(e1, (B(1),));
+
+    let (a, b) = world
+        .borrow::<(View<A, track::Deletion>, View<B>)>()
+        .unwrap();
+
+    assert_eq!(
+        (a.as_deleted(), &b).iter().with_id().collect::<Vec<_>>(),
+        [(e2, (&A(2), &B(2)))]
+    );
+    assert_eq!(a.as_deleted().iter().count(), 2);
+}
+
+#[test]
+fn deleted_twice() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_deletion::<A>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    // makes the deleted components drive the iteration
+    world.bulk_add_entity((1..4).map(B));
+
+    world.delete_component::<(A,)>(e0);
+    world.add_component(e0, (A(1),));
+    world.delete_component::<(A,)>(e0);
+
+    let (a, mut b) = world
+        .borrow::<(View<A, track::Deletion>, ViewMut<B>)>()
+        .unwrap();
+
+    let mut iter = (a.as_deleted(), &mut b).iter().with_id();
+    let (id, (a, mut b)) = iter.next().unwrap();
+    assert!(iter.next().is_none());
+
+    assert_eq!(id, e0);
+    assert_eq!(*a, A(1));
+    b.0 += 1;
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_modification::<(A, B)>();
+
+    world.bulk_add_entity((0..3000).map(|i| (A(i), B(i))));
+
+    world.run(
+        |mut a: ViewMut<A, track::Modification>, mut b: ViewMut<B, track::Modification>| {
+            for (mut a, mut b) in (&mut a, &mut b).iter() {
+                match a.0 % 3 {
+                    0 => a.0 += 1,
+                    1 => b.0 += 1,
+                    _ => {}
+                }
+            }
+        },
+    );
+
+    let (a, b) = world
+        .borrow::<(View<A, track::Modification>, View<B, track::Modification>)>()
+        .unwrap();
+
+    assert_eq!(Changed::new((&a, &b)).par_iter().count(), 2000);
+}
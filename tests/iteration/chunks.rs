@@ -0,0 +1,160 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[test]
+fn chunks() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let entities = (0..10)
+        .map(|i| world.add_entity((U32(i),)))
+        .collect::<Vec<_>>();
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    let chunks = u32s.iter_chunks(4);
+    assert_eq!(chunks.len(), 3);
+
+    let chunks = chunks.collect::<Vec<_>>();
+    assert_eq!(chunks[0].0, &entities[0..4]);
+    assert_eq!(chunks[0].1, [U32(0), U32(1), U32(2), U32(3)]);
+    assert_eq!(chunks[2].0, &entities[8..10]);
+    assert_eq!(chunks[2].1, [U32(8), U32(9)]);
+}
+
+#[test]
+fn chunks_mut() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    for i in 0..10 {
+        world.add_entity((U32(i),));
+    }
+
+    let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+
+    for (entities, u32s) in u32s.iter_chunks_mut(3) {
+        assert_eq!(entities.len(), u32s.len());
+
+        for u32 in u32s {
+            u32.0 *= 2;
+        }
+    }
+
+    assert_eq!(
+        u32s.as_slice(),
+        (0..10).map(|i| U32(i * 2)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn modification_tracking() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.track_modification::<U32>();
+
+    for i in 0..10 {
+        world.add_entity((U32(i),));
+    }
+
+    let mut u32s = world.borrow::<ViewMut<U32, track::Modification>>().unwrap();
+
+    // only yielded chunks are flagged
+    u32s.iter_chunks_mut(4).next();
+    assert_eq!(u32s.modified().iter().count(), 4);
+
+    u32s.iter_chunks_mut(4).for_each(|_| {});
+    assert_eq!(u32s.modified().iter().count(), 10);
+
+    u32s.clear_all_modified();
+
+    let mut u32s = world.borrow::<ViewMut<U32, track::Modification>>().unwrap();
+
+    assert_eq!(u32s.modified().iter().count(), 0);
+    u32s.as_slice_mut()[0].0 = 100;
+    assert_eq!(u32s.modified().iter().count(), 10);
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk_size() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+
+    u32s.iter_chunks(0);
+}
+
+#[test]
+fn grouped() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let mut entities = (0..4)
+        .map(|i| world.add_entity((U32(i), USIZE(i as usize))))
+        .collect::<Vec<_>>();
+    entities.push(world.add_entity((U32(4),)));
+    entities.extend((5..7).map(|i| world.add_entity((U32(i), USIZE(i as usize)))));
+    entities.push(world.add_entity((USIZE(7),)));
+    world.add_entity((U32(8),));
+    world.add_entity((U32(9),));
+
+    let (mut u32s, usizes) = world.borrow::<(ViewMut<U32>, View<USIZE>)>().unwrap();
+
+    let chunks = (&mut u32s, &usizes)
+        .iter_chunks(3)
+        .map(|(entities, (u32s, usizes))| (entities, u32s.to_vec(), usizes.to_vec()))
+        .collect::<Vec<_>>();
+
+    // U32(4) breaks the contiguity and USIZE(7) has no U32
+    assert_eq!(
+        chunks,
+        [
+            (
+                &entities[0..3],
+                vec![U32(0), U32(1), U32(2)],
+                vec![USIZE(0), USIZE(1), USIZE(2)]
+            ),
+            (&entities[3..4], vec![U32(3)], vec![USIZE(3)]),
+            (
+                &entities[5..7],
+                vec![U32(5), U32(6)],
+                vec![USIZE(5), USIZE(6)]
+            ),
+        ]
+    );
+
+    for (_, (u32s, usizes)) in (&mut u32s, &usizes).iter_chunks(8) {
+        for (u32, usize) in u32s.iter_mut().zip(usizes) {
+            u32.0 += usize.0 as u32;
+        }
+    }
+
+    assert_eq!(u32s.as_slice(), [0, 2, 4, 6, 4, 10, 12, 8, 9].map(U32));
+}
+
+#[test]
+fn grouped_modification_tracking() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.track_modification::<U32>();
+
+    for i in 0..10 {
+        world.add_entity((U32(i), USIZE(i as usize)));
+    }
+
+    let (mut u32s, usizes) = world
+        .borrow::<(ViewMut<U32, track::Modification>, View<USIZE>)>()
+        .unwrap();
+
+    // only yielded chunks are flagged
+    (&mut u32s, &usizes).iter_chunks(4).next();
+    assert_eq!(u32s.modified().iter().count(), 4);
+
+    (&mut u32s, &usizes).iter_chunks(4).for_each(|_| {});
+    assert_eq!(u32s.modified().iter().count(), 10);
+}
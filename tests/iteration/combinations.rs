@@ -0,0 +1,126 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+#[test]
+fn pairs() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    for i in 0..4 {
+        world.add_entity((A(i),));
+    }
+
+    let a = world.borrow::<View<A>>().unwrap();
+
+    let pairs = a
+        .iter_combinations::<2>()
+        .map(|[a0, a1]| (a0.0, a1.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(pairs, [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+}
+
+#[test]
+fn triples_of_tuples() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    world.add_entity((A(1),));
+    let e2 = world.add_entity((A(2), B(2)));
+    let e3 = world.add_entity((A(3), B(3)));
+    let e4 = world.add_entity((A(4), B(4)));
+
+    let (a, b) = world.borrow::<(View<A>, View<B>)>().unwrap();
+
+    let mut combinations = (&a, &b).iter_combinations::<3>();
+    let mut count = 0;
+
+    while let Some([(a0, _), (a1, _), (a2, _)]) = combinations.next() {
+        assert!(a0.0 < a1.0 && a1.0 < a2.0);
+        assert_ne!(a1.0, 1);
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert_eq!(combinations.ids(), [e2, e3, e4]);
+
+    let mut combinations = (&a, &b).iter_combinations::<3>();
+    combinations.next();
+    assert_eq!(combinations.ids(), [e0, e2, e3]);
+}
+
+#[test]
+fn not_enough_entities() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0),));
+
+    let a = world.borrow::<View<A>>().unwrap();
+
+    assert_eq!(a.iter_combinations::<2>().count(), 0);
+    assert_eq!(a.iter_combinations::<1>().count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "no combination was yielded yet")]
+fn ids_before_next() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0),));
+    world.add_entity((A(1),));
+
+    let a = world.borrow::<View<A>>().unwrap();
+
+    a.iter_combinations::<2>().ids();
+}
+
+#[test]
+fn mutable() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.track_modification::<A>();
+
+    let entities = (0..4)
+        .map(|i| world.add_entity((A(i),)))
+        .collect::<Vec<_>>();
+
+    world.run(|mut a: ViewMut<A, track::Modification>| {
+        let mut combinations = a.iter_combinations_mut::<2>();
+
+        while let Some([mut a0, a1]) = combinations.fetch_next() {
+            a0.0 += a1.0;
+        }
+    });
+
+    let a = world.borrow::<View<A, track::Modification>>().unwrap();
+
+    assert_eq!(a[entities[0]], A(6));
+    assert_eq!(a[entities[1]], A(6));
+    assert_eq!(a[entities[2]], A(5));
+    assert_eq!(a[entities[3]], A(3));
+    assert_eq!(a.modified().iter().count(), 3);
+}
+
+#[test]
+fn filtered() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0),));
+    world.add_entity((A(1), B(1)));
+    world.add_entity((A(2),));
+    world.add_entity((A(3), B(3)));
+    world.add_entity((A(4),));
+
+    let (a, b) = world.borrow::<(View<A>, View<B>)>().unwrap();
+
+    let pairs = (&a, !&b)
+        .iter_combinations::<2>()
+        .map(|[(a0, _), (a1, _)]| (a0.0, a1.0))
+        .collect::<Vec<_>>();
+
+    assert_eq!(pairs, [(0, 2), (0, 4), (2, 4)]);
+}
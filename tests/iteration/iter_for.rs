@@ -0,0 +1,87 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+#[test]
+fn single() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0),));
+    let e1 = world.add_entity((B(1),));
+    let e2 = world.add_entity((A(2),));
+
+    let a = world.borrow::<View<A>>().unwrap();
+
+    assert_eq!(
+        a.iter_for([e2, e1, e0]).copied().collect::<Vec<_>>(),
+        [A(2), A(0)]
+    );
+}
+
+#[test]
+fn multiple() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1),));
+    let e2 = world.add_entity((A(2), B(2)));
+    let e3 = world.add_entity((B(3),));
+
+    let (mut a, b) = world.borrow::<(ViewMut<A>, View<B>)>().unwrap();
+
+    let visited = (&mut a, &b)
+        .iter_for(vec![e3, e2, e1, e0])
+        .with_id()
+        .map(|(id, (mut a, b))| {
+            a.0 += b.0;
+            id
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(visited, [e2, e0]);
+    assert_eq!(a[e2], A(4));
+    assert_eq!(a[e1], A(1));
+}
+
+#[test]
+fn duplicates_and_dead() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1), B(1)));
+
+    world.delete_entity(e1);
+
+    let (mut a, b) = world.borrow::<(ViewMut<A>, View<B>)>().unwrap();
+
+    assert_eq!(
+        (&mut a, &b)
+            .iter_for([e0, e1, e0, EntityId::dead()])
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn filters() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1),));
+
+    let (a, b) = world.borrow::<(View<A>, View<B>)>().unwrap();
+
+    assert_eq!(
+        (&a, !&b)
+            .iter_for([e0, e1])
+            .map(|(a, _)| *a)
+            .collect::<Vec<_>>(),
+        [A(1)]
+    );
+}
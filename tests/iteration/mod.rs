@@ -1,2 +1,12 @@
+mod any_of;
+mod changed;
+mod chunks;
+mod combinations;
+mod iter_for;
 mod non_packed;
+mod optional;
+#[cfg(feature = "parallel")]
+mod par_chunks;
+mod query_state;
+mod untyped_query;
 mod update;
@@ -0,0 +1,123 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct I16(i16);
+impl Component for I16 {}
+
+fn populated_world() -> World {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let (mut entities, mut u32s, mut i16s) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<I16>)>()
+        .unwrap();
+
+    entities.add_entity((&mut u32s, &mut i16s), (U32(0), I16(10)));
+    entities.add_entity(&mut u32s, U32(1));
+    entities.add_entity(&mut i16s, I16(12));
+    entities.add_entity((&mut u32s, &mut i16s), (U32(3), I16(13)));
+
+    drop((entities, u32s, i16s));
+
+    world
+}
+
+#[test]
+fn basic() {
+    let world = populated_world();
+
+    let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+    assert_eq!(
+        (&u32s, i16s.optional()).iter().collect::<Vec<_>>(),
+        [
+            (&U32(0), Some(&I16(10))),
+            (&U32(1), None),
+            (&U32(3), Some(&I16(13)))
+        ]
+    );
+    assert_eq!(
+        (i16s.optional(), &u32s).iter().collect::<Vec<_>>(),
+        [
+            (Some(&I16(10)), &U32(0)),
+            (None, &U32(1)),
+            (Some(&I16(13)), &U32(3))
+        ]
+    );
+    assert_eq!(
+        (&u32s, i16s.optional()).iter_by::<I16>().count(),
+        3,
+        "an optional storage never drives iteration"
+    );
+    assert_eq!(u32s.optional().iter().count(), 0);
+}
+
+#[test]
+fn with_not() {
+    let world = populated_world();
+
+    let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+    assert_eq!(
+        (&i16s, !&u32s, i16s.optional()).iter().collect::<Vec<_>>(),
+        [(&I16(12), (), Some(&I16(12)))]
+    );
+}
+
+#[test]
+fn mutable() {
+    let world = populated_world();
+
+    let (u32s, mut i16s) = world.borrow::<(View<U32>, ViewMut<I16>)>().unwrap();
+
+    for (u32, i16) in (&u32s, i16s.optional_mut()).iter() {
+        if let Some(mut i16) = i16 {
+            i16.0 += u32.0 as i16;
+        }
+    }
+
+    assert_eq!(
+        i16s.iter().copied().collect::<Vec<_>>(),
+        [I16(10), I16(12), I16(16)]
+    );
+    assert_eq!(
+        (&u32s, i16s.optional()).iter().collect::<Vec<_>>(),
+        [
+            (&U32(0), Some(&I16(10))),
+            (&U32(1), None),
+            (&U32(3), Some(&I16(16)))
+        ]
+    );
+}
+
+#[test]
+fn with_id() {
+    let world = populated_world();
+
+    let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+    for (id, (u32, i16)) in (&u32s, i16s.optional()).iter().with_id() {
+        assert_eq!(u32s[id], *u32);
+        assert_eq!(i16s.get(id).ok(), i16);
+    }
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let world = populated_world();
+
+    let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+    let sum: i32 = (&u32s, i16s.optional())
+        .par_iter()
+        .map(|(u32, i16)| u32.0 as i32 + i16.map_or(100, |i16| i16.0 as i32))
+        .sum();
+
+    assert_eq!(sum, 10 + 101 + 16);
+}
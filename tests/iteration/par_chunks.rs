@@ -0,0 +1,176 @@
+use rayon::prelude::*;
+use shipyard::*;
+use std::sync::Mutex;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+/// Number of items visited by each part rayon splits the iterator into.
+fn part_lens<I: ParallelIterator>(iter: I) -> Vec<usize> {
+    iter.fold(|| 0, |count, _| count + 1).collect()
+}
+
+#[test]
+fn tight_len_hints() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.bulk_add_entity((0..1000).map(A));
+
+    let a = world.borrow::<View<A>>().unwrap();
+
+    let lens = part_lens(a.par_iter().with_max_len(10));
+    assert!(lens.iter().all(|&len| len <= 10));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let lens = part_lens(a.par_iter().with_min_len(300));
+    assert!(lens.iter().all(|&len| len >= 300));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let lens = part_lens(a.par_iter().with_min_len(300).with_max_len(10));
+    assert!(lens.iter().all(|&len| len >= 300));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let sum: u32 = a
+        .par_iter()
+        .with_min_len(7)
+        .with_max_len(50)
+        .map(|a| a.0)
+        .sum();
+    assert_eq!(sum, (0..1000).sum());
+}
+
+#[test]
+fn mixed_len_hints() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    for i in 0..1000 {
+        world.add_entity((A(i), B(i)));
+    }
+
+    let (a, b) = world.borrow::<(View<A>, View<B>)>().unwrap();
+
+    let lens = part_lens((&a, &b).par_iter().with_max_len(10));
+    assert!(lens.iter().all(|&len| len <= 10));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let lens = part_lens((&a, &b).par_iter().with_min_len(300));
+    assert!(lens.iter().all(|&len| len >= 300));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let lens = part_lens((&a, &b).par_iter().with_min_len(300).with_max_len(10));
+    assert!(lens.iter().all(|&len| len >= 300));
+    assert_eq!(lens.iter().sum::<usize>(), 1000);
+
+    let sum: u32 = (&a, &b)
+        .par_iter()
+        .with_min_len(7)
+        .with_max_len(50)
+        .map(|(a, b)| a.0 + b.0)
+        .sum();
+    assert_eq!(sum, 2 * (0..1000).sum::<u32>());
+}
+
+#[test]
+fn tight_for_each_chunk() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.bulk_add_entity((0..1000).map(A));
+
+    let chunks = Mutex::new(Vec::new());
+
+    world
+        .borrow::<ViewMut<A>>()
+        .unwrap()
+        .par_iter()
+        .par_for_each_chunk(64, |chunk| {
+            let chunk = chunk.map(|a| a.0).collect::<Vec<_>>();
+            chunks.lock().unwrap().push(chunk);
+        });
+
+    let mut chunks = chunks.into_inner().unwrap();
+    chunks.sort_unstable();
+
+    assert_eq!(chunks.len(), 16);
+    assert!(chunks[..15].iter().all(|chunk| chunk.len() == 64));
+    assert_eq!(
+        chunks.concat(),
+        (0..1000).collect::<Vec<_>>(),
+        "chunks are contiguous"
+    );
+}
+
+#[test]
+fn mixed_for_each_chunk() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    for i in 0..1000 {
+        if i % 3 == 0 {
+            world.add_entity((A(i),));
+        } else {
+            world.add_entity((A(i), B(i)));
+        }
+    }
+
+    let chunks = Mutex::new(Vec::new());
+
+    let (mut a, b) = world.borrow::<(ViewMut<A>, View<B>)>().unwrap();
+
+    (&mut a, &b).par_iter().par_for_each_chunk(50, |chunk| {
+        let chunk = chunk.map(|(a, b)| (a.0, b.0)).collect::<Vec<_>>();
+        assert!(chunk.len() <= 50);
+        chunks.lock().unwrap().push(chunk);
+    });
+
+    let mut items = chunks.into_inner().unwrap().concat();
+    items.sort_unstable();
+
+    assert_eq!(
+        items,
+        (0..1000)
+            .filter(|i| i % 3 != 0)
+            .map(|i| (i, i))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic(expected = "chunk_size must be greater than 0")]
+fn zero_chunk_size() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((A(0),));
+
+    world
+        .borrow::<View<A>>()
+        .unwrap()
+        .par_iter()
+        .par_for_each_chunk(0, |_| {});
+}
+
+#[test]
+fn local_thread_pool() {
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+    let mut world =
+        World::new_with_custom_lock_and_local_thread_pool::<parking_lot::RawRwLock>(thread_pool);
+
+    world.bulk_add_entity((0..1000).map(A));
+
+    let num_threads = world.install(|| {
+        world.run(|a: View<A>| {
+            a.par_iter()
+                .with_max_len(10)
+                .map(|_| rayon::current_num_threads())
+                .max()
+        })
+    });
+
+    assert_eq!(num_threads, Some(3));
+}
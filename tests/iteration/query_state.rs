@@ -0,0 +1,140 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct I16(i16);
+impl Component for I16 {}
+
+#[test]
+fn cache() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((U32(0), I16(0)));
+    world.add_entity((U32(1),));
+    let e2 = world.add_entity((U32(2), I16(2)));
+
+    let mut query = QueryState::new();
+
+    {
+        let (mut u32s, i16s) = world.borrow::<(ViewMut<U32>, View<I16>)>().unwrap();
+
+        for (mut u32, i16) in query.iter((&mut u32s, &i16s)) {
+            u32.0 += i16.0 as u32 + 10;
+        }
+        assert_eq!(query.entities(), [e0, e2]);
+
+        // modifying components keeps the cache
+        u32s[e0].0 = 100;
+        assert_eq!(
+            query.iter((&u32s, &i16s)).collect::<Vec<_>>(),
+            [(&U32(100), &I16(0)), (&U32(14), &I16(2))]
+        );
+    }
+
+    let e3 = world.add_entity((U32(3), I16(3)));
+    {
+        let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+        assert_eq!(
+            query
+                .iter((&u32s, &i16s))
+                .with_id()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            [e0, e2, e3]
+        );
+    }
+
+    world.remove::<I16>(e0);
+    world.delete_entity(e2);
+    {
+        let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+        assert_eq!(
+            query.iter((&u32s, &i16s)).collect::<Vec<_>>(),
+            [(&U32(3), &I16(3))]
+        );
+        assert_eq!(query.entities(), [e3]);
+    }
+}
+
+#[test]
+fn not() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_entity((U32(0), I16(0)));
+    let e1 = world.add_entity((U32(1),));
+
+    let mut query = QueryState::new();
+
+    let (u32s, i16s) = world.borrow::<(View<U32>, View<I16>)>().unwrap();
+
+    assert_eq!(
+        query.iter((&u32s, !&i16s)).collect::<Vec<_>>(),
+        [(&U32(1), ())]
+    );
+    assert_eq!(query.entities(), [e1]);
+}
+
+#[test]
+fn tracking_is_not_cached() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.track_modification::<U32>();
+
+    let e0 = world.add_entity((U32(0),));
+    let e1 = world.add_entity((U32(1),));
+
+    let mut query = QueryState::new();
+
+    {
+        let mut u32s = world.borrow::<ViewMut<U32, track::Modification>>().unwrap();
+        u32s[e0].0 = 10;
+
+        assert_eq!(query.iter(u32s.modified()).collect::<Vec<_>>(), [&U32(10)]);
+
+        u32s.clear_all_modified();
+    }
+
+    let mut u32s = world.borrow::<ViewMut<U32, track::Modification>>().unwrap();
+    u32s[e1].0 = 11;
+
+    assert_eq!(query.iter(u32s.modified()).collect::<Vec<_>>(), [&U32(11)]);
+}
+
+#[test]
+fn local() {
+    fn sum(
+        mut query: Local<QueryState>,
+        u32s: View<U32>,
+        i16s: View<I16>,
+        mut total: UniqueViewMut<Total>,
+    ) {
+        total.0 = query.iter((&u32s, &i16s)).map(|(u32, _)| u32.0).sum();
+    }
+
+    struct Total(u32);
+    impl Unique for Total {}
+
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Total(0));
+    world.add_entity((U32(1), I16(0)));
+    world.add_entity((U32(2),));
+
+    Workload::new("Sum")
+        .with_system(sum)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Sum").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Total>>().unwrap().0, 1);
+
+    world.add_entity((U32(4), I16(0)));
+
+    world.run_workload("Sum").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Total>>().unwrap().0, 5);
+}
@@ -0,0 +1,123 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct A(u32);
+impl Component for A {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct B(u32);
+impl Component for B {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct C;
+impl Component for C {}
+
+#[test]
+fn include_exclude() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0), B(0)));
+    let e1 = world.add_entity((A(1),));
+    let e2 = world.add_entity((A(2), B(2), C));
+    let e3 = world.add_entity((A(3), B(3)));
+
+    let all_storages = world.borrow::<AllStoragesView>().unwrap();
+    let query = all_storages
+        .untyped_query(
+            &[
+                StorageId::of::<SparseSet<A>>(),
+                StorageId::of::<SparseSet<B>>(),
+            ],
+            &[StorageId::of::<SparseSet<C>>()],
+        )
+        .unwrap();
+
+    let matched = query
+        .iter()
+        .map(|(id, components)| {
+            assert_eq!(components.id(), id);
+            assert_eq!(components.len(), 2);
+
+            (
+                id,
+                *components.get(0).unwrap().downcast_ref::<A>().unwrap(),
+                *components.get(1).unwrap().downcast_ref::<B>().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(matched, [(e0, A(0), B(0)), (e3, A(3), B(3))]);
+
+    assert!(query.contains(e0));
+    assert!(!query.contains(e1));
+    assert!(!query.contains(e2));
+    assert!(query.get(e1).is_none());
+    assert_eq!(
+        query
+            .get(e3)
+            .unwrap()
+            .iter()
+            .map(|component| component.is_some())
+            .collect::<Vec<_>>(),
+        [true, true]
+    );
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0),));
+
+    let all_storages = world.borrow::<AllStoragesView>().unwrap();
+
+    let query = all_storages
+        .untyped_query(
+            &[
+                StorageId::of::<SparseSet<A>>(),
+                StorageId::of::<SparseSet<B>>(),
+            ],
+            &[],
+        )
+        .unwrap();
+    assert_eq!(query.iter().count(), 0);
+    assert!(!query.contains(e0));
+
+    let query = all_storages
+        .untyped_query(
+            &[StorageId::of::<SparseSet<A>>()],
+            &[StorageId::of::<SparseSet<C>>()],
+        )
+        .unwrap();
+    assert_eq!(query.iter().map(|(id, _)| id).collect::<Vec<_>>(), [e0]);
+}
+
+#[test]
+fn deleted_entity() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let e0 = world.add_entity((A(0),));
+    world.delete_entity(e0);
+    let e1 = world.add_entity((A(1),));
+
+    let all_storages = world.borrow::<AllStoragesView>().unwrap();
+    let query = all_storages
+        .untyped_query(&[StorageId::of::<SparseSet<A>>()], &[])
+        .unwrap();
+
+    assert!(!query.contains(e0));
+    assert!(query.contains(e1));
+}
+
+#[test]
+fn borrow_conflict() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_entity((A(0),));
+
+    let _a = world.borrow::<ViewMut<A>>().unwrap();
+    let all_storages = world.borrow::<AllStoragesView>().unwrap();
+
+    assert!(all_storages
+        .untyped_query(&[StorageId::of::<SparseSet<A>>()], &[])
+        .is_err());
+}
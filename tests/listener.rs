@@ -0,0 +1,120 @@
+use shipyard::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(PartialEq, Eq, Debug)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+fn recorder<T: Send + 'static>() -> (Arc<Mutex<Vec<T>>>, impl FnMut(T) + Send + Sync + 'static) {
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let record_clone = record.clone();
+
+    (record, move |value| {
+        record_clone.lock().unwrap().push(value)
+    })
+}
+
+#[test]
+fn entity_listeners() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let (created, mut on_creation) = recorder();
+    let (deleted0, mut on_deletion0) = recorder();
+    let (deleted1, mut on_deletion1) = recorder();
+
+    world.on_creation(move |entity| on_creation(entity));
+    let listener0 = world.on_deletion(move |entity| on_deletion0(entity));
+    world.on_deletion(move |entity| on_deletion1(entity));
+
+    let entity0 = world.add_entity((USIZE(0),));
+    let entity1 = world.add_entity(());
+    let bulk = world
+        .bulk_add_entity((0..2).map(|i| (U32(i),)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        *created.lock().unwrap(),
+        vec![entity0, entity1, bulk[0], bulk[1]]
+    );
+
+    world.delete_entity(entity0);
+
+    assert!(world.remove_entity_listener(listener0));
+    assert!(!world.remove_entity_listener(listener0));
+
+    world.delete_entity(entity1);
+
+    assert_eq!(*deleted0.lock().unwrap(), vec![entity0]);
+    assert_eq!(*deleted1.lock().unwrap(), vec![entity0, entity1]);
+}
+
+#[test]
+fn spawn_listener() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let (spawned, mut on_spawn) = recorder();
+    world.on_spawn(move |entity| on_spawn(entity));
+
+    let entity = EntityId::new_from_index_and_gen(3, 0);
+
+    assert!(world.spawn(entity));
+
+    assert_eq!(*spawned.lock().unwrap(), vec![entity]);
+}
+
+#[test]
+fn component_listeners() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let (inserted, mut on_insert) = recorder();
+    let (removed, mut on_remove) = recorder();
+
+    world.on_insert::<USIZE>(move |entity, component| on_insert((entity, component.0)));
+    let listener =
+        world.on_remove::<USIZE>(move |entity, component| on_remove((entity, component.0)));
+
+    let entity0 = world.add_entity((USIZE(0),));
+    let entity1 = world.add_entity((USIZE(1), U32(0)));
+    let entity2 = world.add_entity((USIZE(2),));
+    let entity3 = world.add_entity((USIZE(3),));
+    let bulk = world
+        .bulk_add_entity((4..6).map(|i| (USIZE(i), U32(0))))
+        .collect::<Vec<_>>();
+    world.add_component(entity0, (USIZE(10),));
+
+    assert_eq!(
+        *inserted.lock().unwrap(),
+        vec![
+            (entity0, 0),
+            (entity1, 1),
+            (entity2, 2),
+            (entity3, 3),
+            (bulk[0], 4),
+            (bulk[1], 5),
+            (entity0, 10)
+        ]
+    );
+
+    world.delete_entity(entity0);
+    world.strip(entity1);
+    world.remove::<(USIZE,)>(entity2);
+    world.delete_component::<(USIZE,)>(entity3);
+    // the entity doesn't have the component anymore
+    world.delete_component::<(USIZE,)>(entity3);
+
+    assert_eq!(
+        *removed.lock().unwrap(),
+        vec![(entity0, 10), (entity1, 1), (entity2, 2), (entity3, 3)]
+    );
+
+    assert!(world.remove_component_listener::<USIZE>(listener));
+    assert!(!world.remove_component_listener::<USIZE>(listener));
+
+    world.delete_entity(bulk[0]);
+
+    assert_eq!(removed.lock().unwrap().len(), 4);
+}
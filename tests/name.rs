@@ -0,0 +1,118 @@
+use core::any::type_name;
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[test]
+fn entity_by_name() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    assert_eq!(world.entity_by_name("player_ship"), None);
+
+    let player = world.add_entity((Name::new("player_ship"),));
+    let enemy = world.add_entity((Name::new(String::from("enemy_ship")),));
+
+    assert_eq!(world.entity_by_name("player_ship"), Some(player));
+    assert_eq!(world.entity_by_name("enemy_ship"), Some(enemy));
+    assert_eq!(world.entity_by_name("asteroid"), None);
+
+    world.delete_entity(player);
+
+    assert_eq!(world.entity_by_name("player_ship"), None);
+}
+
+#[test]
+fn missing_component() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let unnamed = world.add_entity(());
+    let player = world.add_entity((Name::new("player_ship"),));
+
+    world.run(|u32s: View<U32>, names: View<Name>| {
+        let err = u32s.get(player).unwrap_err().with_names(&names);
+
+        assert_eq!(
+            err.error(),
+            error::MissingComponent {
+                id: player,
+                name: type_name::<U32>(),
+            }
+        );
+        assert_eq!(err.entity_name(), Some("player_ship"));
+        assert_eq!(
+            format!("{:?}", err),
+            format!(
+                "player_ship ({:?}) does not have a {} component.",
+                player,
+                type_name::<U32>()
+            )
+        );
+
+        let err = u32s.get(unnamed).unwrap_err().with_names(&names);
+
+        assert_eq!(err.entity_name(), None);
+    });
+}
+
+#[test]
+#[should_panic(expected = "player_ship (")]
+fn index_panic() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let player = world.add_entity((Name::new("player_ship"),));
+
+    world.run(|u32s: View<U32>| {
+        let _ = &u32s[player];
+    });
+}
+
+#[test]
+#[should_panic(expected = "player_ship (")]
+fn index_mut_panic() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let player = world.add_entity((Name::new("player_ship"),));
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s[player].0 += 1;
+    });
+}
+
+#[test]
+fn debug_with_names() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let player = world.add_entity((Name::new("player_ship"), U32(0)));
+    let unnamed = world.add_entity((U32(1),));
+
+    world.run(|u32s: View<U32>, names: View<Name>| {
+        assert_eq!(
+            format!("{:?}", u32s.debug_with_names(&names)),
+            format!(
+                "[(player_ship ({:?}), U32(0)), ({:?}, U32(1))]",
+                player, unnamed
+            )
+        );
+    });
+}
+
+#[test]
+fn view_debug() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let player = world.add_entity((Name::new("player_ship"), U32(0)));
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(
+            format!("{:?}", u32s),
+            format!("[(player_ship ({:?}), U32(0))]", player)
+        );
+    });
+
+    // the names can't be borrowed so raw ids are printed
+    world.run(|u32s: View<U32>, _names: ViewMut<Name>| {
+        assert_eq!(format!("{:?}", u32s), format!("[({:?}, U32(0))]", player));
+    });
+}
@@ -0,0 +1,136 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct USIZE(usize);
+impl Component for USIZE {}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Link(EntityId);
+impl Component for Link {}
+
+impl MapEntities for Link {
+    fn map_entities(&mut self, map: &mut dyn FnMut(EntityId) -> EntityId) {
+        self.0 = map(self.0);
+    }
+}
+
+#[test]
+fn spawn() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let mut prefab = Prefab::new();
+    prefab.add_component(prefab.root(), (USIZE(0), U32(1)));
+
+    world.add_prefab("prefab", prefab);
+
+    let entity0 = world.spawn_prefab("prefab").unwrap();
+    let entity1 = world.spawn_prefab("prefab").unwrap();
+
+    assert_ne!(entity0, entity1);
+
+    world.run(|usizes: View<USIZE>, u32s: View<U32>| {
+        assert_eq!((&usizes, &u32s).get(entity0), Ok((&USIZE(0), &U32(1))));
+        assert_eq!((&usizes, &u32s).get(entity1), Ok((&USIZE(0), &U32(1))));
+    });
+}
+
+#[test]
+fn missing() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    assert!(!world.contains_prefab("prefab"));
+    assert_eq!(
+        world.spawn_prefab("prefab"),
+        Err(error::SpawnPrefab::MissingPrefab)
+    );
+    assert_eq!(
+        world.bulk_spawn_prefab("prefab", 2).err(),
+        Some(error::SpawnPrefab::MissingPrefab)
+    );
+}
+
+#[test]
+fn remap() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let outside = world.add_entity((U32(0),));
+
+    let mut prefab = Prefab::new();
+    let child = prefab.add_entity();
+    prefab.add_component(child, (U32(1),));
+    prefab.add_component_with_entities(child, Link(prefab.root()));
+    prefab.add_component_with_entities(prefab.root(), Link(child));
+    prefab.add_component(prefab.root(), (U32(2),));
+
+    let mut linked = Prefab::new();
+    linked.add_component_with_entities(linked.root(), Link(outside));
+
+    world.add_prefab("prefab", prefab);
+    world.add_prefab("linked", linked);
+
+    let root = world.spawn_prefab("prefab").unwrap();
+    let linked = world.spawn_prefab("linked").unwrap();
+
+    world.run(|links: View<Link>, u32s: View<U32>| {
+        let child = links[root].0;
+
+        assert_ne!(child, root);
+        assert_eq!(links[child], Link(root));
+        assert_eq!(u32s[root], U32(2));
+        assert_eq!(u32s[child], U32(1));
+
+        // ids outside the prefab are left untouched
+        assert_eq!(links[linked], Link(outside));
+    });
+}
+
+#[test]
+fn bulk() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let mut prefab = Prefab::new();
+    let child = prefab.add_entity();
+    prefab.add_component(child, (USIZE(1),));
+    prefab.add_component_with_entities(prefab.root(), Link(child));
+    prefab.add_component(prefab.root(), (U32(0),));
+
+    world.add_prefab("prefab", prefab);
+
+    let roots = world
+        .bulk_spawn_prefab("prefab", 3)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(roots.len(), 3);
+
+    world.run(|links: View<Link>, u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.len(), 3);
+        assert_eq!(usizes.len(), 3);
+
+        let mut children = Vec::new();
+        for &root in &roots {
+            assert_eq!(u32s[root], U32(0));
+
+            let child = links[root].0;
+            assert_eq!(usizes[child], USIZE(1));
+            assert!(!children.contains(&child));
+            assert!(!roots.contains(&child));
+
+            children.push(child);
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "is not part of this prefab")]
+fn foreign_entity() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    let entity = world.add_entity(());
+
+    let mut prefab = Prefab::new();
+    prefab.add_component(entity, (U32(0),));
+}
@@ -213,3 +213,119 @@ fn new_world() {
         EntityId::new_from_index_and_gen(6, 0)
     );
 }
+
+#[test]
+fn local_range() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.set_local_entity_range(10..20);
+
+    let local0 = world.add_entity((U32(0),));
+    let local1 = world.add_entity((U32(1),));
+    assert_eq!(local0.index(), 10);
+    assert_eq!(local1.index(), 11);
+
+    // remote ids outside of the local range
+    let remote0 = EntityId::new_from_index_and_gen(0, 0);
+    let remote1 = EntityId::new_from_index_and_gen(25, 3);
+    assert!(world.spawn(remote0));
+    assert!(world.spawn(remote1));
+    assert!(!world.spawn(EntityId::new_from_index_and_gen(12, 0)));
+
+    // deleted local entities are reused, remote free indices are not
+    world.delete_entity(local0);
+    world.delete_entity(remote0);
+    let local2 = world.add_entity(());
+    assert_eq!(local2.index(), 10);
+    assert_eq!(local2.gen(), 1);
+
+    let bulk = world
+        .bulk_add_entity((0..3).map(|i| (U32(i),)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        bulk.iter().map(|entity| entity.index()).collect::<Vec<_>>(),
+        vec![12, 13, 14]
+    );
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert!(entities.is_alive(local1));
+    assert!(entities.is_alive(local2));
+    assert!(entities.is_alive(remote1));
+    assert!(!entities.is_alive(remote0));
+    assert!(bulk.iter().all(|&entity| entities.is_alive(entity)));
+    assert_eq!(entities.iter().count(), 6);
+}
+
+#[test]
+fn local_range_existing_entities() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity(());
+    let entity2 = world.add_entity(());
+    world.delete_entity(entity1);
+
+    world.set_local_entity_range(1..5);
+
+    // index 1 is free but below the alive index 2
+    let local0 = world.add_entity(());
+    let local1 = world.add_entity(());
+    assert_eq!(local0.index(), 1);
+    assert_eq!(local1.index(), 3);
+
+    world.remove_local_entity_range();
+    assert!(world.spawn(EntityId::new_from_index_and_gen(4, 0)));
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert!(entities.is_alive(entity0));
+    assert!(entities.is_alive(entity2));
+}
+
+#[test]
+#[should_panic(expected = "is exhausted")]
+fn local_range_exhausted() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.set_local_entity_range(0..2);
+
+    world.add_entity(());
+    world.add_entity(());
+    world.add_entity(());
+}
+
+#[test]
+fn local_range_clear() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.set_local_entity_range(2..4);
+
+    let local0 = world.add_entity(());
+    assert_eq!(local0.index(), 2);
+    // pads the local range
+    assert!(world.spawn(EntityId::new_from_index_and_gen(5, 0)));
+
+    world.clear();
+
+    // the local indices were taken out of the shared list again
+    let local1 = world.add_entity(());
+    let local2 = world.add_entity(());
+    assert_eq!(local1.index(), 2);
+    assert_eq!(local1.gen(), 1);
+    assert_eq!(local2.index(), 3);
+    assert!(!world.spawn(EntityId::new_from_index_and_gen(3, 5)));
+}
+
+#[test]
+fn local_range_padding() {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.set_local_entity_range(2..4);
+
+    // indices 0, 1 and 4 are free, 2 and 3 are unused local indices
+    assert!(world.spawn(EntityId::new_from_index_and_gen(5, 0)));
+
+    world.remove_local_entity_range();
+
+    let mut indices = (0..5)
+        .map(|_| world.add_entity(()).index())
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+
+    assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+    assert_eq!(world.add_entity(()).index(), 6);
+}
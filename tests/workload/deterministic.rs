@@ -0,0 +1,145 @@
+use rayon::prelude::*;
+use shipyard::*;
+use std::sync::Mutex;
+use std::thread::ThreadId;
+
+struct Value(f32);
+impl Component for Value {}
+
+struct Other(f32);
+impl Component for Other {}
+
+#[derive(Default)]
+struct Sum(f32);
+impl Unique for Sum {}
+
+fn tight_sum(values: View<Value>, mut sum: UniqueViewMut<Sum>) {
+    sum.0 = values.par_iter().map(|value| value.0).sum();
+}
+
+fn mixed_sum(values: View<Value>, others: View<Other>, mut sum: UniqueViewMut<Sum>) {
+    sum.0 = (&values, &others)
+        .par_iter()
+        .map(|(value, other)| value.0 * other.0)
+        .sum();
+}
+
+fn populated_world() -> World {
+    let mut world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Sum::default());
+
+    for i in 0..10_000u32 {
+        let value = Value(1.0 / (i as f32 + 1.0));
+
+        if i % 3 == 0 {
+            world.add_entity((value, Other(i as f32 * 0.1)));
+        } else {
+            world.add_entity(value);
+        }
+    }
+
+    world
+}
+
+#[test]
+fn par_iter_sum() {
+    let world = populated_world();
+
+    world.set_deterministic(true);
+    assert!(world.is_deterministic());
+
+    Workload::new("Tight")
+        .with_system(tight_sum)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Mixed")
+        .with_system(mixed_sum)
+        .add_to_world(&world)
+        .unwrap();
+
+    for workload in ["Tight", "Mixed"] {
+        world.run_workload(workload).unwrap();
+        let expected = world.borrow::<UniqueView<Sum>>().unwrap().0;
+
+        for _ in 0..20 {
+            world.run_workload(workload).unwrap();
+
+            assert_eq!(
+                world.borrow::<UniqueView<Sum>>().unwrap().0.to_bits(),
+                expected.to_bits()
+            );
+        }
+    }
+}
+
+#[test]
+fn run_workload_deterministic() {
+    let world = populated_world();
+
+    Workload::new("Tight")
+        .with_system(tight_sum)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(!world.is_deterministic());
+
+    world.run_workload_deterministic("Tight").unwrap();
+    let expected = world.borrow::<UniqueView<Sum>>().unwrap().0;
+
+    for _ in 0..20 {
+        world.run_workload_deterministic("Tight").unwrap();
+
+        assert_eq!(
+            world.borrow::<UniqueView<Sum>>().unwrap().0.to_bits(),
+            expected.to_bits()
+        );
+    }
+}
+
+#[test]
+fn sequential_order() {
+    static ORDER: Mutex<Vec<(&str, ThreadId)>> = Mutex::new(Vec::new());
+
+    fn record(name: &'static str) {
+        ORDER
+            .lock()
+            .unwrap()
+            .push((name, std::thread::current().id()));
+    }
+
+    fn a(_: View<Value>) {
+        record("a");
+    }
+    fn b(_: View<Other>) {
+        record("b");
+    }
+    fn c(_: ViewMut<Value>) {
+        record("c");
+    }
+    fn d(_: View<Other>) {
+        record("d");
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Order")
+        .with_system(a)
+        .with_system(b)
+        .with_system(c)
+        .with_system(d)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..10 {
+        world.run_workload_deterministic("Order").unwrap();
+
+        let order = std::mem::take(&mut *ORDER.lock().unwrap());
+
+        assert_eq!(
+            order.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["a", "b", "c", "d"]
+        );
+        assert!(order.iter().all(|(_, thread)| *thread == order[0].1));
+    }
+}
@@ -0,0 +1,107 @@
+use shipyard::*;
+
+#[derive(Default)]
+struct Count(u32);
+impl Unique for Count {}
+
+fn count(mut count: UniqueViewMut<Count>) {
+    count.0 += 1;
+}
+fn other() {}
+fn faulty(_: UniqueView<Count>) {
+    panic!("Faulty system");
+}
+
+fn is_enabled(info: &info::WorkloadInfo, system: &str) -> bool {
+    info.batch_info
+        .iter()
+        .flat_map(|batch_info| batch_info.systems.0.iter().chain(&batch_info.systems.1))
+        .find(|system_info| format!("{:?}", system_info.name).contains(system))
+        .unwrap()
+        .enabled
+}
+
+#[test]
+fn world() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Count(0));
+
+    let info = Workload::new("Count")
+        .with_system(count)
+        .with_system(other)
+        .add_to_world(&world)
+        .unwrap();
+    assert!(is_enabled(&info, "count"));
+
+    world.set_system_enabled(count, false).unwrap();
+    world.run_workload("Count").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 0);
+
+    let info = world.workload_info("Count").unwrap();
+    assert!(!is_enabled(&info, "count"));
+    assert!(is_enabled(&info, "other"));
+
+    world
+        .set_system_enabled(core::any::type_name_of_val(&count), true)
+        .unwrap();
+    world.run_workload("Count").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+    assert!(is_enabled(&world.workload_info("Count").unwrap(), "count"));
+}
+
+#[test]
+fn missing_system() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Other")
+        .with_system(other)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.set_system_enabled(count, false),
+        Err(error::SetSystemEnabled::MissingSystem)
+    );
+    assert!(world.workload_info("Count").is_none());
+}
+
+#[test]
+fn scheduled_workload() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Count(0));
+
+    let (workload, _) = Workload::new("Count").with_system(count).build().unwrap();
+
+    workload.set_system_enabled(count, false).unwrap();
+    workload.run_with_world(&world).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 0);
+    assert!(!is_enabled(&workload.workload_info(), "count"));
+
+    workload.set_system_enabled(count, true).unwrap();
+    workload.run_with_world(&world).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+}
+
+#[test]
+fn disabled_by_panic_policy() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Count(0));
+
+    Workload::new("Faulty")
+        .with_system(faulty)
+        .on_panic(PanicPolicy::DisableSystem)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.run_workload("Faulty").is_err());
+    assert!(!is_enabled(
+        &world.workload_info("Faulty").unwrap(),
+        "faulty"
+    ));
+
+    world.set_system_enabled(faulty, true).unwrap();
+    assert!(world.run_workload("Faulty").is_err());
+}
@@ -0,0 +1,157 @@
+use shipyard::*;
+
+struct Collision(u32);
+
+#[derive(Default)]
+struct Read(Vec<u32>);
+impl Unique for Read {}
+
+#[derive(Default)]
+struct OtherRead(Vec<u32>);
+impl Unique for OtherRead {}
+
+fn send(mut collisions: EventWriter<Collision>, mut sent: Local<u32>) {
+    collisions.send(Collision(*sent));
+    *sent += 1;
+}
+fn read(mut collisions: EventReader<Collision>, mut read: UniqueViewMut<Read>) {
+    read.0
+        .extend(collisions.iter().map(|collision| collision.0));
+}
+fn other_read(mut collisions: EventReader<Collision>, mut read: UniqueViewMut<OtherRead>) {
+    read.0
+        .extend(collisions.iter().map(|collision| collision.0));
+}
+fn nothing() {}
+
+#[test]
+fn readers_keep_their_cursor() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Read::default());
+    world.add_unique(OtherRead::default());
+
+    let info = Workload::new("Events")
+        .with_system(send)
+        .with_system(read)
+        .with_system(other_read)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(info.batch_info[1].systems.1.len(), 2);
+
+    world.run_workload("Events").unwrap();
+    world.run_workload("Events").unwrap();
+    world.run_workload("Events").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Read>>().unwrap().0, [0, 1, 2]);
+    assert_eq!(
+        world.borrow::<UniqueView<OtherRead>>().unwrap().0,
+        [0, 1, 2]
+    );
+}
+
+#[test]
+fn expire_after_two_runs() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Read::default());
+
+    Workload::new("Send")
+        .with_system(send)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Read")
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Nothing")
+        .with_system(nothing)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Send").unwrap();
+    world.run_workload("Read").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Read>>().unwrap().0, [0]);
+
+    world.run_workload("Send").unwrap();
+    world.run_workload("Nothing").unwrap();
+    world.run_workload("Read").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Read>>().unwrap().0, [0]);
+
+    world.run(|collisions: EventReader<Collision>| {
+        assert!(collisions.is_empty());
+    });
+}
+
+#[test]
+fn expire_after_two_updates() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.run(|mut collisions: EventWriter<Collision>| {
+        collisions.send(Collision(0));
+    });
+
+    world.update_events();
+    world.run(|collisions: EventReader<Collision>| {
+        assert_eq!(collisions.len(), 1);
+    });
+
+    world.update_events();
+    world.run(|collisions: EventReader<Collision>| {
+        assert!(collisions.is_empty());
+    });
+}
+
+#[test]
+fn readers_in_the_same_system() {
+    fn two_readers(
+        mut first: EventReader<Collision>,
+        mut second: EventReader<Collision>,
+        mut read: UniqueViewMut<Read>,
+        mut other_read: UniqueViewMut<OtherRead>,
+    ) {
+        read.0.extend(first.iter().map(|collision| collision.0));
+        other_read
+            .0
+            .extend(second.iter().map(|collision| collision.0));
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Read::default());
+    world.add_unique(OtherRead::default());
+
+    Workload::new("Events")
+        .with_system(send)
+        .with_system(two_readers)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Events").unwrap();
+    world.run_workload("Events").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Read>>().unwrap().0, [0, 1]);
+    assert_eq!(world.borrow::<UniqueView<OtherRead>>().unwrap().0, [0, 1]);
+}
+
+#[test]
+fn outside_workload() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.run(|mut collisions: EventWriter<Collision>| {
+        collisions.send_batch([Collision(0), Collision(1)]);
+    });
+
+    world.run(|mut collisions: EventReader<Collision>| {
+        assert_eq!(collisions.len(), 2);
+        assert_eq!(collisions.iter().count(), 2);
+        assert!(collisions.is_empty());
+    });
+
+    // cursors are not kept outside workloads
+    world.run(|collisions: EventReader<Collision>| {
+        assert_eq!(collisions.len(), 2);
+    });
+}
@@ -0,0 +1,92 @@
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {}
+
+struct USIZE(usize);
+impl Component for USIZE {}
+
+fn spawn(all_storages: &mut AllStorages) {
+    all_storages.add_entity((U32(0),));
+}
+fn no_borrow() {}
+fn other_no_borrow() {}
+fn read(u32s: View<U32>, mut usizes: ViewMut<USIZE>) {
+    usizes.clear();
+
+    for (id, _) in u32s.iter().with_id() {
+        usizes.add_component_unchecked(id, USIZE(0));
+    }
+}
+fn other_read(_: View<U32>) {}
+
+#[test]
+fn exclusive_system() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Exclusive")
+        .with_system(no_borrow)
+        .with_system(other_read)
+        .with_system(spawn)
+        .with_system(other_no_borrow)
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 3);
+    assert!(!info.batch_info[0].is_exclusive());
+    assert_eq!(info.batch_info[0].systems.1.len(), 2);
+
+    assert!(info.batch_info[1].is_exclusive());
+    assert!(info.batch_info[1].systems.1.is_empty());
+    let system_info = info.batch_info[1].systems.0.as_ref().unwrap();
+    assert!(system_info.exclusive);
+    assert_eq!(
+        format!("{:?}", system_info.name),
+        format!("{:?}", core::any::type_name_of_val(&spawn))
+    );
+
+    assert!(!info.batch_info[2].is_exclusive());
+    assert_eq!(info.batch_info[2].systems.1.len(), 2);
+
+    world.run_workload("Exclusive").unwrap();
+    world.run_workload("Exclusive").unwrap();
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.len(), 2);
+        assert_eq!(usizes.len(), 2);
+    });
+
+    assert!(info.to_dot().contains("(exclusive)"));
+}
+
+#[test]
+fn single_exclusive_system() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Exclusive")
+        .with_system(spawn)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(info.batch_info[0].is_exclusive());
+
+    world.run_workload("Exclusive").unwrap();
+
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
+
+#[test]
+fn exclusive_closure() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Exclusive")
+        .with_system(|all_storages: &mut AllStorages| {
+            all_storages.add_entity((U32(1),));
+        })
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Exclusive").unwrap();
+    assert_eq!(world.borrow::<View<U32>>().unwrap().len(), 1);
+}
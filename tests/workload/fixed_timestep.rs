@@ -0,0 +1,153 @@
+use core::time::Duration;
+use shipyard::*;
+
+struct Steps(u32);
+impl Unique for Steps {}
+
+fn step(mut steps: UniqueViewMut<Steps>) {
+    steps.0 += 1;
+}
+
+fn advance(world: &World, millis: u64) {
+    world.run(|mut time: UniqueViewMut<Time>| time.advance(Duration::from_millis(millis)));
+}
+
+fn steps(world: &World) -> u32 {
+    world.borrow::<UniqueView<Steps>>().unwrap().0
+}
+
+fn alpha(world: &World) -> f32 {
+    world.borrow::<UniqueView<FixedTimestepAlpha>>().unwrap().0
+}
+
+#[test]
+fn fixed_timestep() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Time::new());
+    world.add_unique(Steps(0));
+
+    Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10))
+        .add_to_world(&world)
+        .unwrap();
+
+    advance(&world, 4);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 0);
+    assert_eq!(alpha(&world), 0.4);
+
+    advance(&world, 8);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 1);
+    assert_eq!(alpha(&world), 0.2);
+
+    advance(&world, 30);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 4);
+    assert_eq!(alpha(&world), 0.2);
+
+    // time did not advance
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 4);
+}
+
+#[test]
+fn max_catch_up_steps() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Time::new());
+    world.add_unique(Steps(0));
+
+    Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10))
+        .max_catch_up_steps(2)
+        .add_to_world(&world)
+        .unwrap();
+
+    advance(&world, 55);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 2);
+    assert_eq!(alpha(&world), 0.5);
+
+    // the dropped steps are not caught up later
+    advance(&world, 5);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 3);
+    assert_eq!(alpha(&world), 0.0);
+}
+
+#[test]
+fn skipped_fixed_timestep() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Time::new());
+    world.add_unique(Steps(0));
+
+    Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10))
+        .skip_if(|steps: UniqueView<Steps>| steps.0 == 0)
+        .add_to_world(&world)
+        .unwrap();
+
+    advance(&world, 20);
+    world.run_workload("Fixed").unwrap();
+    assert_eq!(steps(&world), 0);
+    assert!(world.borrow::<UniqueView<FixedTimestepAlpha>>().is_err());
+}
+
+#[test]
+fn missing_time() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Steps(0));
+
+    Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10))
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(matches!(
+        world.run_workload("Fixed"),
+        Err(error::RunWorkload::Run((
+            _,
+            error::Run::GetStorage(error::GetStorage::MissingStorage { .. })
+        )))
+    ));
+}
+
+#[test]
+fn scheduled_fixed_timestep() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Time::new());
+    world.add_unique(Steps(0));
+
+    let (workload, _) = Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10))
+        .build()
+        .unwrap();
+
+    advance(&world, 25);
+    workload.run_with_world(&world).unwrap();
+    assert_eq!(steps(&world), 2);
+}
+
+#[test]
+fn nested_fixed_timestep() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let fixed = Workload::new("Fixed")
+        .with_system(step)
+        .fixed_timestep(Duration::from_millis(10));
+
+    let label: Box<dyn Label> = Box::new("Fixed");
+    assert_eq!(
+        Workload::new("Parent")
+            .with_system(|| {})
+            .merge(fixed)
+            .add_to_world(&world)
+            .err(),
+        Some(error::AddWorkload::NestedFixedTimestep(label))
+    );
+}
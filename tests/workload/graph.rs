@@ -0,0 +1,69 @@
+use core::any::type_name;
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {}
+
+fn read(_: View<U32>) {}
+fn write(_: ViewMut<U32>) {}
+fn other() {}
+
+fn name<T>(_: T) -> &'static str {
+    type_name::<T>()
+}
+
+#[test]
+fn to_dot() {
+    let (_, info) = Workload::new("Graph")
+        .with_system(read)
+        .with_system(write)
+        .with_system(other.before_all(read))
+        .build()
+        .unwrap();
+
+    assert_eq!(info.orderings.len(), 1);
+    assert_eq!(
+        format!("{:?}", info.orderings[0].0),
+        format!("{:?}", name(other))
+    );
+    assert_eq!(
+        format!("{:?}", info.orderings[0].1),
+        format!("{:?}", name(read))
+    );
+
+    let dot = info.to_dot();
+
+    assert!(dot.starts_with("digraph \"Graph\" {\n"));
+    assert!(dot.contains(&format!("s0 [label=\"{}\"];", name(other))));
+    assert!(dot.contains(&format!("s1 [label=\"{}\"];", name(read))));
+    assert!(dot.contains(&format!("s2 [label=\"{}\"];", name(write))));
+    assert!(dot.contains("subgraph cluster_2 {"));
+    assert!(dot.contains("    s0 -> s1;\n"));
+    assert!(dot.contains(&format!(
+        "    s1 -> s2 [style=dashed, label=\"&{storage} / &mut {storage}\"];\n",
+        storage = type_name::<SparseSet<U32>>()
+    )));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn to_mermaid() {
+    let (_, info) = Workload::new("Graph")
+        .with_system(read)
+        .with_system(write)
+        .build()
+        .unwrap();
+
+    let mermaid = info.to_mermaid();
+
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("    subgraph batch_0[\"Batch 0\"]\n"));
+    assert!(mermaid.contains(&format!("        s0[\"{}\"]\n", name(read))));
+    assert!(mermaid.contains(&format!(
+        "    s0 -.->|\"&{storage} / &mut {storage}\"| s1\n",
+        storage = type_name::<SparseSet<U32>>()
+            .replace('<', "#lt;")
+            .replace('>', "#gt;")
+    )));
+    assert!(!mermaid.contains("-->"));
+}
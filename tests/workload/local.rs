@@ -0,0 +1,130 @@
+use shipyard::*;
+
+#[derive(Default)]
+struct Runs(u32);
+impl Unique for Runs {}
+
+#[derive(Default)]
+struct Other(u32);
+impl Unique for Other {}
+
+fn count(mut local: Local<u32>, mut runs: UniqueViewMut<Runs>) {
+    *local += 1;
+    runs.0 = *local;
+}
+fn other_count(mut local: Local<u32>, mut other: UniqueViewMut<Other>) {
+    *local += 10;
+    other.0 = *local;
+}
+fn first(mut local: Local<u32>) {
+    *local += 1;
+}
+fn second(mut local: Local<u32>) {
+    *local += 1;
+}
+
+#[test]
+fn persists_between_runs() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+    world.add_unique(Other(0));
+
+    Workload::new("Local")
+        .with_system(count)
+        .with_system(other_count)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Local").unwrap();
+    world.run_workload("Local").unwrap();
+    world.run_workload("Local").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 3);
+    assert_eq!(world.borrow::<UniqueView<Other>>().unwrap().0, 30);
+}
+
+#[test]
+fn no_conflict() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Local")
+        .with_system(first)
+        .with_system(second)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 1);
+    assert_eq!(info.batch_info[0].systems.1.len(), 2);
+    assert!(info.batch_info[0]
+        .systems
+        .1
+        .iter()
+        .all(|system| system.borrow.is_empty() && system.conflict.is_none()));
+
+    world.run_workload("Local").unwrap();
+}
+
+#[test]
+fn run_gets_default() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    world.run(count);
+    world.run(count);
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 1);
+}
+
+#[test]
+fn same_type_locals() {
+    fn two_locals(mut a: Local<u32>, mut b: Local<u32>, mut runs: UniqueViewMut<Runs>) {
+        *a += 1;
+        *b += 10;
+        runs.0 = *a + *b;
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    Workload::new("Local")
+        .with_system(two_locals)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Local").unwrap();
+    world.run_workload("Local").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 22);
+}
+
+#[test]
+fn custom_view() {
+    #[derive(Borrow, BorrowInfo)]
+    struct Counter<'v> {
+        local: Local<'v, u32>,
+        runs: UniqueViewMut<'v, Runs>,
+    }
+
+    fn count_in_view(mut counter: Counter) {
+        *counter.local += 1;
+        counter.runs.0 = *counter.local;
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    Workload::new("Local")
+        .with_system(count_in_view)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Local").unwrap();
+    world.run_workload("Local").unwrap();
+    world.run_workload("Local").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 3);
+}
@@ -1,5 +1,16 @@
+#[cfg(feature = "parallel")]
+mod deterministic;
+mod enabled;
+mod event;
+mod exclusive;
+mod fixed_timestep;
+mod graph;
+mod local;
 #[cfg(feature = "thread_local")]
 mod non_send_sync;
+mod panic;
+mod profiler;
+mod system_set;
 
 struct U32(u32);
 impl Component for U32 {}
@@ -0,0 +1,156 @@
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {}
+
+#[derive(Default)]
+struct Runs(u32);
+impl Unique for Runs {}
+
+fn faulty(_: ViewMut<U32>) {
+    panic!("Faulty system");
+}
+fn count(mut runs: UniqueViewMut<Runs>) {
+    runs.0 += 1;
+}
+
+fn workload_world(policy: PanicPolicy) -> World {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    Workload::new("Panic")
+        .with_system(faulty)
+        .with_barrier()
+        .with_system(count)
+        .on_panic(policy)
+        .add_to_world(&world)
+        .unwrap();
+
+    world
+}
+
+fn assert_panic(result: Result<(), error::RunWorkload>) {
+    match result {
+        Err(error::RunWorkload::Panic((label, message))) => {
+            assert_eq!(
+                format!("{:?}", label),
+                format!("{:?}", core::any::type_name_of_val(&faulty))
+            );
+            assert_eq!(message, "Faulty system");
+        }
+        _ => panic!("Expected a panic error"),
+    }
+}
+
+#[test]
+fn abort() {
+    let world = workload_world(PanicPolicy::Abort);
+
+    assert_panic(world.run_workload("Panic"));
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 0);
+
+    // borrows were released
+    world.borrow::<ViewMut<U32>>().unwrap();
+    assert_panic(world.run_workload("Panic"));
+}
+
+#[test]
+fn skip_system() {
+    let world = workload_world(PanicPolicy::SkipSystem);
+
+    assert_panic(world.run_workload("Panic"));
+    assert_panic(world.run_workload("Panic"));
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 2);
+    world.borrow::<ViewMut<U32>>().unwrap();
+}
+
+#[test]
+fn disable_system() {
+    let world = workload_world(PanicPolicy::DisableSystem);
+
+    assert_panic(world.run_workload("Panic"));
+    world.run_workload("Panic").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 2);
+
+    world.set_system_enabled(faulty, true).unwrap();
+    assert_panic(world.run_workload("Panic"));
+}
+
+#[test]
+fn parallel_batch() {
+    fn other(_: View<U32>) {}
+    fn other_faulty(_: View<U32>) {
+        panic!("Faulty system");
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Panic")
+        .with_system(other)
+        .with_system(other_faulty)
+        .on_panic(PanicPolicy::SkipSystem)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(matches!(
+        world.run_workload("Panic"),
+        Err(error::RunWorkload::Panic(_))
+    ));
+    world.borrow::<ViewMut<U32>>().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Faulty system")]
+fn no_policy() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Panic")
+        .with_system(faulty)
+        .add_to_world(&world)
+        .unwrap();
+
+    let _ = world.run_workload("Panic");
+}
+
+#[test]
+fn merged_policy() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    Workload::new("Panic")
+        .with_system(count)
+        .merge(
+            Workload::new("Child")
+                .with_system(faulty)
+                .on_panic(PanicPolicy::SkipSystem),
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_panic(world.run_workload("Panic"));
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 1);
+}
+
+#[test]
+#[should_panic(expected = "Faulty system")]
+fn merged_policy_does_not_leak() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Runs(0));
+
+    Workload::new("Panic")
+        .with_system(faulty)
+        .merge(
+            Workload::new("Child")
+                .with_system(count)
+                .on_panic(PanicPolicy::SkipSystem),
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    let _ = world.run_workload("Panic");
+}
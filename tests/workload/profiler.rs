@@ -0,0 +1,80 @@
+use core::any::type_name;
+use core::time::Duration;
+use shipyard::*;
+
+struct Enabled(bool);
+impl Unique for Enabled {}
+
+fn slow() {
+    std::thread::sleep(Duration::from_millis(2));
+}
+fn conditional() {}
+
+fn name<T>(_: T) -> String {
+    format!("{:?}", type_name::<T>())
+}
+
+#[test]
+fn workload_stats() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+    world.add_unique(Enabled(false));
+
+    Workload::new("Profiled")
+        .with_system(slow)
+        .with_system(conditional.run_if(|enabled: UniqueView<Enabled>| enabled.0))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Profiled").unwrap();
+    assert!(world.workload_stats("Profiled").is_none());
+
+    world.enable_profiling();
+    world.run_workload("Profiled").unwrap();
+    world.run_workload("Profiled").unwrap();
+    world.run(|mut enabled: UniqueViewMut<Enabled>| enabled.0 = true);
+    world.run_workload("Profiled").unwrap();
+
+    let stats = world.workload_stats("Profiled").unwrap();
+    assert_eq!(stats.runs, 3);
+    assert_eq!(stats.systems.len(), 2);
+
+    let slow_stats = stats
+        .systems
+        .iter()
+        .find(|system| format!("{:?}", system.name) == name(slow))
+        .unwrap();
+    assert_eq!(slow_stats.calls, 3);
+    assert_eq!(slow_stats.skipped, 0);
+    assert!(slow_stats.total_time >= Duration::from_millis(6));
+    assert!(slow_stats.max_time >= slow_stats.last_time);
+    assert!(slow_stats.mean_time() >= Duration::from_millis(2));
+
+    let conditional_stats = stats
+        .systems
+        .iter()
+        .find(|system| format!("{:?}", system.name) == name(conditional))
+        .unwrap();
+    assert_eq!(conditional_stats.calls, 1);
+    assert_eq!(conditional_stats.skipped, 2);
+
+    world.disable_profiling();
+    assert!(world.workload_stats("Profiled").is_none());
+}
+
+#[test]
+fn default_workload_stats() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    Workload::new("Default")
+        .with_system(slow)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.enable_profiling();
+    world.run_default().unwrap();
+
+    let stats = world.workload_stats("Default").unwrap();
+    assert_eq!(stats.runs, 1);
+    assert_eq!(stats.systems[0].calls, 1);
+    assert!(world.workload_stats("Other").is_none());
+}
@@ -0,0 +1,112 @@
+use shipyard::*;
+
+#[derive(Default)]
+struct Paused(bool);
+impl Unique for Paused {}
+
+#[derive(Default)]
+struct Runs(u32);
+impl Unique for Runs {}
+
+fn render() {}
+fn physics() {}
+fn collisions() {}
+fn count(mut runs: UniqueViewMut<Runs>) {
+    runs.0 += 1;
+}
+
+fn name_of<T>(_: T) -> String {
+    format!("{:?}", core::any::type_name::<T>())
+}
+
+fn batch_names(info: &info::WorkloadInfo, batch: usize) -> Vec<String> {
+    let systems = &info.batch_info[batch].systems;
+
+    systems
+        .0
+        .iter()
+        .chain(&systems.1)
+        .map(|system| format!("{:?}", system.name))
+        .collect()
+}
+
+#[test]
+fn set_ordering() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Frame")
+        .with_system(render.tag("render"))
+        .with_system(physics.tag("physics"))
+        .with_system(collisions.tag("physics"))
+        .configure_set(SystemSet::new("physics").before_all("render"))
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(
+        batch_names(&info, 0),
+        [name_of(physics), name_of(collisions)]
+    );
+    assert_eq!(batch_names(&info, 1), [name_of(render)]);
+
+    world.run_workload("Frame").unwrap();
+}
+
+#[test]
+fn set_in_nested_workload() {
+    fn simulation() -> Workload {
+        (physics.tag("physics"), collisions.tag("physics")).into_workload()
+    }
+
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let info = Workload::new("Frame")
+        .with_system(render.tag("render"))
+        .merge(simulation())
+        .configure_set(SystemSet::new("render").after_all("physics"))
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(batch_names(&info, 1), [name_of(render)]);
+}
+
+#[test]
+fn set_run_if() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    world.add_unique(Paused(false));
+    world.add_unique(Runs(0));
+
+    Workload::new("Frame")
+        .with_system(count.tag("simulation"))
+        .with_system(render)
+        .configure_set(SystemSet::new("simulation").skip_if(|paused: UniqueView<Paused>| paused.0))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Frame").unwrap();
+    world.run(|mut paused: UniqueViewMut<Paused>| paused.0 = true);
+    world.run_workload("Frame").unwrap();
+    world.run(|mut paused: UniqueViewMut<Paused>| paused.0 = false);
+    world.run_workload("Frame").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Runs>>().unwrap().0, 2);
+}
+
+#[test]
+fn set_loop() {
+    let world = World::new_with_custom_lock::<parking_lot::RawRwLock>();
+
+    let result = Workload::new("Frame")
+        .with_system(render.tag("render"))
+        .with_system(physics.tag("physics"))
+        .configure_set(SystemSet::new("physics").before_all("render"))
+        .configure_set(SystemSet::new("render").before_all("physics"))
+        .add_to_world(&world);
+
+    assert!(matches!(
+        result,
+        Err(error::AddWorkload::ImpossibleRequirements(_))
+    ));
+}